@@ -1,21 +1,32 @@
-use std::ops::Div;
-
-use crate::{define_measurement, define_units, prelude::Velocity, time::Time};
+use crate::{define_measurement, define_units, length::Length, time::Time, velocity::Velocity};
 
 define_measurement! {
     /// A measurement of acceleration, with a base unit of meters per second squared.
-    Acceleration
+    Acceleration = [length: 1, time: -2]
 }
 
 define_units! { Acceleration =>
-    meters_per_second_squared: ("m/s²", 1.0),
-    standard_gravity: ("g", 9.80665),
+    meters_per_second_squared: ("m/s²", 1.0, Si, "meter per second squared", "meters per second squared"),
+    standard_gravity: ("g", 9.80665, Other, "standard gravity", "standard gravities"),
 }
 
-impl Div<Time> for Velocity {
-    type Output = Acceleration;
-    fn div(self, rhs: Time) -> Self::Output {
-        Acceleration(self.0 / rhs.0)
+/// Motion under this constant acceleration.
+impl Acceleration {
+    /// The time to go from `initial` to `target` velocity (`v = u + at`).
+    pub fn time_to_reach(self, initial: Velocity, target: Velocity) -> Time {
+        (target - initial) / self
+    }
+
+    /// The distance covered in `time`, starting at `initial` velocity (`s = ut + ½at²`).
+    pub fn distance_over(self, initial: Velocity, time: Time) -> Length {
+        initial * time + self * time * time * 0.5
+    }
+
+    /// The speed after covering `distance`, starting at `initial` velocity (`v² = u² + 2as`),
+    /// or `None` if the motion reverses before getting that far.
+    pub fn speed_after(self, initial: Velocity, distance: Length) -> Option<Velocity> {
+        let squared = initial.0 * initial.0 + 2.0 * self.0 * distance.0;
+        (squared >= 0.0).then(|| Velocity(squared.sqrt()))
     }
 }
 
@@ -28,4 +39,13 @@ mod tests {
         let acc = Acceleration::new::<standard_gravity>(1.0);
         assert_eq!(acc.as_ref(), &9.80665);
     }
+
+    #[test]
+    fn constant_acceleration() {
+        let acceleration = Acceleration(2.0);
+        assert_eq!(acceleration.time_to_reach(Velocity(4.0), Velocity(10.0)), Time(3.0));
+        assert_eq!(acceleration.distance_over(Velocity(4.0), Time(3.0)), Length(21.0));
+        assert_eq!(acceleration.speed_after(Velocity(4.0), Length(21.0)), Some(Velocity(10.0)));
+        assert_eq!(Acceleration(-2.0).speed_after(Velocity(4.0), Length(5.0)), None);
+    }
 }
\ No newline at end of file
@@ -3,7 +3,7 @@ use std::f64::consts::PI;
 
 define_measurement! {
     /// A measurement of an angle, with a base unit of radians.
-    Angle
+    Angle = [angle: 1]
 }
 
 impl Angle {
@@ -15,8 +15,8 @@ impl Angle {
 }
 
 define_units! { Angle =>
-    radian: ("rad", 1.0),
-    degree: ("°", PI / 180.0),
+    radian: ("rad", 1.0, Si, "radian", "radians"),
+    degree: ("°", PI / 180.0, Metric, "degree", "degrees"),
 }
 
 
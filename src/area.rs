@@ -0,0 +1,40 @@
+use crate::{define_measurement, define_units, length::Length};
+
+define_measurement! {
+    /// A measurement of area, with a base unit of square meters.
+    Area = [length: 2]
+}
+
+define_units! { Area =>
+    square_millimeter: ("mm²", 1.0e-6, Si, "square millimeter", "square millimeters"),
+    square_centimeter: ("cm²", 1.0e-4, Si, "square centimeter", "square centimeters"),
+    square_meter: ("m²", 1.0, Si, "square meter", "square meters"),
+    hectare: ("ha", 1.0e4, Metric, "hectare", "hectares"),
+    square_kilometer: ("km²", 1.0e6, Si, "square kilometer", "square kilometers"),
+}
+
+impl Area {
+    /// The side of a square with this area.
+    pub fn sqrt(self) -> Length {
+        Length(self.0.sqrt())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_works() {
+        let area = Area::new::<hectare>(1.0);
+        assert_eq!(area.as_ref(), &10_000.0);
+        assert_eq!(area.get::<square_kilometer>(), 0.01);
+        assert_eq!(area.to_string(), "1.00 ha");
+        assert_eq!(Area(25.0).to_string(), "25.00 m²");
+    }
+
+    #[test]
+    fn sqrt_is_length() {
+        assert_eq!(Area::new::<square_kilometer>(4.0).sqrt(), Length(2000.0));
+    }
+}
@@ -0,0 +1,23 @@
+use crate::{define_measurement, define_units};
+
+define_measurement! {
+    /// A measurement of capacitance, with a base unit of farads.
+    Capacitance = [mass: -1, length: -2, time: 4, current: 2]
+}
+
+define_units! { Capacitance =>
+    #[si_prefixes]
+    farad: ("F", 1.0, Si, "farad", "farads"),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_works() {
+        let capacitance = Capacitance::new::<microfarad>(100.0);
+        assert!((capacitance.0 - 1e-4).abs() < 1e-18);
+        assert_eq!(capacitance.to_string(), "100.00 µF");
+    }
+}
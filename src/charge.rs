@@ -0,0 +1,39 @@
+use crate::{define_measurement, define_units};
+
+define_measurement! {
+    /// A measurement of electric charge, with a base unit of coulombs.
+    ElectricCharge = [current: 1, time: 1]
+}
+
+define_units! { ElectricCharge =>
+    #[si_prefixes]
+    coulomb: ("C", 1.0, Si, "coulomb", "coulombs"),
+    #[explicit]
+    milliampere_hour: ("mA·h", 3.6, Metric, "milliampere-hour", "milliampere-hours"),
+    #[explicit]
+    ampere_hour: ("A·h", 3600.0, Metric, "ampere-hour", "ampere-hours"),
+    // The same units as they are usually written on batteries.
+    #[explicit]
+    milliampere_hour_compact: ("mAh", 3.6, Metric, "milliampere-hour", "milliampere-hours"),
+    #[explicit]
+    ampere_hour_compact: ("Ah", 3600.0, Metric, "ampere-hour", "ampere-hours"),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::display::DisplayPolicy;
+
+    #[test]
+    fn battery_capacity() {
+        let capacity = ElectricCharge::new::<milliampere_hour>(3000.0);
+        assert!((capacity.get::<ampere_hour>() - 3.0).abs() < 1e-12);
+        assert_eq!(capacity.to_string(), "10.80 kC");
+
+        let policy = DisplayPolicy::default().fix::<milliampere_hour>();
+        assert_eq!(capacity.display_with(&policy).to_string(), "3000.00 mA·h");
+        assert_eq!("2.5 A·h".parse(), Ok(ElectricCharge(9000.0)));
+        assert_eq!("2.5 Ah".parse(), Ok(ElectricCharge(9000.0)));
+        assert_eq!("3000 mAh".parse(), Ok(capacity));
+    }
+}
@@ -0,0 +1,23 @@
+use crate::{define_measurement, define_units};
+
+define_measurement! {
+    /// A measurement of electric current, with a base unit of amperes.
+    ElectricCurrent = [current: 1]
+}
+
+define_units! { ElectricCurrent =>
+    #[si_prefixes]
+    ampere: ("A", 1.0, Si, "ampere", "amperes"),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_works() {
+        let current = ElectricCurrent::new::<milliampere>(250.0);
+        assert_eq!(current, ElectricCurrent(0.25));
+        assert_eq!(current.to_string(), "250.00 mA");
+    }
+}
@@ -2,11 +2,25 @@ use crate::{define_measurement, define_units};
 
 define_measurement! {
     /// A measurement of density, with a base unit of kilograms per cubic meter.
-    MassDensity
+    MassDensity = [mass: 1, length: -3]
 }
 
 define_units! { MassDensity =>
-    kilogram_per_cubic_meter: ("kg/m³", 1.0),
-    kilogram_per_cubic_centimeter: ("kg/cm³", 1.0_E3),
-    gram_per_cubic_centimeter: ("g/cm³", 1.0_E6)
+    #[prefix(KILO)]
+    kilogram_per_cubic_meter: ("kg/m³", 1.0, Si, "kilogram per cubic meter", "kilograms per cubic meter"),
+    gram_per_cubic_centimeter: ("g/cm³", 1.0_E3, Si, "gram per cubic centimeter", "grams per cubic centimeter"),
+    #[prefix(KILO)]
+    kilogram_per_cubic_centimeter: ("kg/cm³", 1.0_E6, Si, "kilogram per cubic centimeter", "kilograms per cubic centimeter"),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_works() {
+        let water = MassDensity::new::<gram_per_cubic_centimeter>(1.0);
+        assert_eq!(water.as_ref(), &1000.0);
+        assert_eq!(MassDensity::new::<kilogram_per_cubic_centimeter>(1.0), MassDensity(1.0e6));
+    }
 }
@@ -0,0 +1,253 @@
+//! Control over which unit a measurement is displayed in.
+
+use std::{
+    fmt,
+    sync::{PoisonError, RwLock},
+};
+
+use crate::{BesiUnit, Measurement, UnitDisplayInfo, UnitSystem};
+
+static GLOBAL_POLICY: RwLock<DisplayPolicy> = RwLock::new(DisplayPolicy::new());
+
+/// Decides which registered units `Display` may pick for a measurement.
+///
+/// The global policy is used by every `Display` impl, and a specific policy can
+/// be used for a single call with `display_with`:
+///
+/// ```
+/// use besi::prelude::*;
+///
+/// let policy = DisplayPolicy::si().fix::<meter>();
+/// assert_eq!(Length(1500.0).display_with(&policy).to_string(), "1500.00 m");
+/// assert_eq!(Time(7200.0).display_with(&policy).to_string(), "7200.00 s");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DisplayPolicy {
+    systems: Option<Vec<UnitSystem>>,
+    excluded: Vec<(&'static str, &'static str)>,
+    fixed: Vec<(&'static str, &'static str)>,
+    all_prefixes: bool,
+}
+
+impl DisplayPolicy {
+    /// A policy that considers every registered unit.
+    pub const fn new() -> Self {
+        Self {
+            systems: None,
+            excluded: Vec::new(),
+            fixed: Vec::new(),
+            all_prefixes: false,
+        }
+    }
+
+    /// Only SI units (m, km, s, ms, W, ...).
+    pub fn si() -> Self {
+        Self::new().with_systems(&[UnitSystem::Si])
+    }
+
+    /// SI units and the everyday units accepted alongside them (minute, hour, liter, ...).
+    pub fn common() -> Self {
+        Self::new().with_systems(&[UnitSystem::Si, UnitSystem::Metric])
+    }
+
+    /// SI units and astronomical units (AU, solar mass, ...).
+    pub fn astronomical() -> Self {
+        Self::new().with_systems(&[UnitSystem::Si, UnitSystem::Astronomical])
+    }
+
+    /// Restricts selection to units of the given systems.
+    ///
+    /// Measurements without any unit in these systems fall back to all their units.
+    pub fn with_systems(mut self, systems: &[UnitSystem]) -> Self {
+        self.systems = Some(systems.to_vec());
+        self
+    }
+
+    /// Never selects the unit `U`.
+    pub fn exclude<U: BesiUnit>(mut self) -> Self {
+        self.excluded.push((U::Measurement::NAME, U::SYMBOL));
+        self
+    }
+
+    /// Always displays `U`'s measurement in `U`.
+    pub fn fix<U: BesiUnit>(mut self) -> Self {
+        self.fixed
+            .retain(|(measurement, _)| *measurement != U::Measurement::NAME);
+        self.fixed.push((U::Measurement::NAME, U::SYMBOL));
+        self
+    }
+
+    /// Also selects units with the centi, deci, deca and hecto prefixes.
+    ///
+    /// By default only prefixes whose exponent is a multiple of three are selected
+    /// among the units generated by `#[si_prefixes]`, so `150 W` isn't shown as `1.50 hW`.
+    pub fn with_all_prefixes(mut self) -> Self {
+        self.all_prefixes = true;
+        self
+    }
+
+    /// Returns a copy of the global policy.
+    pub fn global() -> Self {
+        GLOBAL_POLICY
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Replaces the global policy used by every `Display` impl.
+    pub fn set_global(policy: Self) {
+        *GLOBAL_POLICY
+            .write()
+            .unwrap_or_else(PoisonError::into_inner) = policy;
+    }
+
+    /// Whether `unit` may be selected by this policy, ignoring fixed units.
+    pub fn allows(&self, unit: &UnitDisplayInfo) -> bool {
+        let system_allowed = self
+            .systems
+            .as_ref()
+            .is_none_or(|systems| systems.contains(&unit.system));
+        system_allowed && !self.is_excluded(unit)
+    }
+
+    fn is_excluded(&self, unit: &UnitDisplayInfo) -> bool {
+        let prefix_excluded = unit
+            .prefix
+            .is_some_and(|prefix| !self.all_prefixes && !prefix.is_engineering());
+        prefix_excluded
+            || self
+                .excluded
+                .contains(&(unit.measurement_type_name, unit.symbol))
+    }
+
+    /// Selects the unit to display `value` (in base units) of `measurement` in.
+    pub fn select(&self, measurement: &str, value: f64) -> &'static UnitDisplayInfo {
+        let fixed = self
+            .fixed
+            .iter()
+            .find(|(name, _)| *name == measurement)
+            .and_then(|(_, symbol)| {
+                UnitDisplayInfo::units_of(measurement).find(|unit| unit.symbol == *symbol)
+            });
+
+        fixed
+            .or_else(|| UnitDisplayInfo::best_of(measurement, value, |unit| self.allows(unit)))
+            .or_else(|| {
+                UnitDisplayInfo::best_of(measurement, value, |unit| !self.is_excluded(unit))
+            })
+            .unwrap_or_else(|| UnitDisplayInfo::best_for(measurement, value))
+    }
+
+    /// Selects a unit using the global policy.
+    pub fn select_global(measurement: &str, value: f64) -> &'static UnitDisplayInfo {
+        GLOBAL_POLICY
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .select(measurement, value)
+    }
+}
+
+/// Displays a measurement according to a specific [`DisplayPolicy`].
+///
+/// Created by the `display_with` method of every measurement.
+pub struct MeasurementDisplay<'a, M> {
+    value: M,
+    policy: &'a DisplayPolicy,
+}
+
+impl<'a, M: Measurement> MeasurementDisplay<'a, M> {
+    pub fn new(value: M, policy: &'a DisplayPolicy) -> Self {
+        Self { value, policy }
+    }
+}
+
+impl<M: Measurement> fmt::Display for MeasurementDisplay<'_, M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = self.value.to_base();
+        let unit = self.policy.select(M::NAME, value);
+        crate::format::write_unit(f, unit.from_base(value), unit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Mutex, PoisonError};
+
+    use crate::prelude::*;
+
+    #[test]
+    fn restricts_systems() {
+        let length = Length::new::<earth_radius>(2.0);
+        assert_eq!(length.to_string(), "2.00 R⊕");
+        assert_eq!(
+            length.display_with(&DisplayPolicy::si()).to_string(),
+            "12742.00 km"
+        );
+
+        let time = Time::new::<day_sidereal>(1.0);
+        assert_eq!(time.to_string(), "1.00 d'");
+        assert_eq!(
+            time.display_with(&DisplayPolicy::common()).to_string(),
+            "23.93 h"
+        );
+        assert_eq!(
+            Time::new::<month>(2.0)
+                .display_with(&DisplayPolicy::common())
+                .to_string(),
+            "60.00 d"
+        );
+    }
+
+    #[test]
+    fn excludes_and_fixes_units() {
+        let policy = DisplayPolicy::new().exclude::<kilometer>();
+        assert_eq!(
+            Length(1500.0).display_with(&policy).to_string(),
+            "1500.00 m"
+        );
+
+        let policy = DisplayPolicy::new().fix::<minute>();
+        assert_eq!(Time(7200.0).display_with(&policy).to_string(), "120.00 min");
+        assert_eq!(Length(1500.0).display_with(&policy).to_string(), "1.50 km");
+    }
+
+    #[test]
+    fn selects_prefixed_units() {
+        assert_eq!(Power(150.0).to_string(), "150.00 W");
+        assert_eq!(Power(2.5e-4).to_string(), "250.00 µW");
+        assert_eq!(Power(0.0).to_string(), "0.00 W");
+        assert_eq!(
+            Power(150.0)
+                .display_with(&DisplayPolicy::new().with_all_prefixes())
+                .to_string(),
+            "1.50 hW"
+        );
+        assert_eq!(
+            Pressure(150.0)
+                .display_with(&DisplayPolicy::new().exclude::<pascal>().with_all_prefixes())
+                .to_string(),
+            "1.50 hPa"
+        );
+    }
+
+    #[test]
+    fn falls_back_when_no_unit_matches() {
+        let policy = DisplayPolicy::new().with_systems(&[UnitSystem::Imperial]);
+        assert_eq!(Length(1500.0).display_with(&policy).to_string(), "1.50 km");
+    }
+
+    /// Held by tests that change the global policy, which is shared by every test.
+    static GLOBAL_POLICY_LOCK: Mutex<()> = Mutex::new(());
+
+    #[test]
+    fn global_policy_applies_to_display() {
+        let _lock = GLOBAL_POLICY_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+        let previous = DisplayPolicy::global();
+
+        DisplayPolicy::set_global(previous.clone().fix::<pascal>());
+        let fixed = Pressure::new::<bar>(1.0).to_string();
+        DisplayPolicy::set_global(previous);
+        assert_eq!(fixed, "100000.00 Pa");
+        assert_eq!(Pressure::new::<bar>(1.0).to_string(), "1.00 bar");
+    }
+}
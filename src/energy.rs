@@ -2,27 +2,43 @@ use crate::{define_measurement, define_units};
 
 define_measurement! {
     /// A measurement of energy, with a base unit of joules.
-    Energy
+    Energy = [mass: 1, length: 2, time: -2]
 }
 
 define_measurement! {
-    /// A measurement of energy, with a base unit of joules.
-    MolarEnergy
+    /// A measurement of energy per amount of substance, with a base unit of joules per mole.
+    MolarEnergy = [mass: 1, length: 2, time: -2, amount: -1]
 }
 
 
 define_units! { Energy =>
-    joule: ("J", 1.0),
-    kilojoule: ("kJ", 1000.0),
-    megajoule: ("MJ", 1.0e6),
+    #[si_prefixes]
+    joule: ("J", 1.0, Si, "joule", "joules"),
+    #[explicit]
+    watt_hour: ("W·h", 3600.0, Metric, "watt-hour", "watt-hours"),
+    #[explicit]
+    kilowatt_hour: ("kW·h", 3.6e6, Metric, "kilowatt-hour", "kilowatt-hours"),
+    #[explicit]
+    megawatt_hour: ("MW·h", 3.6e9, Metric, "megawatt-hour", "megawatt-hours"),
+    // The same units as they are usually written on meters and bills.
+    #[explicit]
+    watt_hour_compact: ("Wh", 3600.0, Metric, "watt-hour", "watt-hours"),
+    #[explicit]
+    kilowatt_hour_compact: ("kWh", 3.6e6, Metric, "kilowatt-hour", "kilowatt-hours"),
+    #[explicit]
+    megawatt_hour_compact: ("MWh", 3.6e9, Metric, "megawatt-hour", "megawatt-hours"),
 }
 
 define_units! { MolarEnergy =>
-    joule_per_mole: ("J/mol", 1.0),
-    kilojoule_per_mole: ("kJ/mol", 1000.0),
-    megajoulee_per_mole: ("MJ/mol", 1.0e6),
+    joule_per_mole: ("J/mol", 1.0, Si, "joule per mole", "joules per mole"),
+    kilojoule_per_mole: ("kJ/mol", 1000.0, Si, "kilojoule per mole", "kilojoules per mole"),
+    megajoule_per_mole: ("MJ/mol", 1.0e6, Si, "megajoule per mole", "megajoules per mole"),
 }
 
+#[deprecated(note = "renamed to `megajoule_per_mole`")]
+#[allow(non_camel_case_types)]
+pub type megajoulee_per_mole = megajoule_per_mole;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -32,4 +48,22 @@ mod tests {
         let energy = Energy::new::<kilojoule>(1.0);
         assert_eq!(energy.as_ref(), &1000.0);
     }
+
+    #[test]
+    fn molar_energy() {
+        let enthalpy = MolarEnergy::new::<kilojoule_per_mole>(-890.0);
+        assert_eq!(enthalpy.get::<megajoule_per_mole>(), -0.89);
+        assert_eq!(enthalpy.to_string(), "-890.00 kJ/mol");
+    }
+
+    #[test]
+    fn watt_hours() {
+        let energy = Energy::new::<kilowatt_hour>(2.0);
+        assert_eq!(energy.get::<megajoule>(), 7.2);
+        assert_eq!(energy.to_string(), "7.20 MJ");
+        assert_eq!("50 W·h".parse(), Ok(Energy(180_000.0)));
+        assert_eq!("50 Wh".parse(), Ok(Energy(180_000.0)));
+        assert_eq!("5 kWh".parse::<Energy>(), Ok(Energy(1.8e7)));
+        assert_eq!("2 MWh".parse::<Energy>(), Ok(Energy(7.2e9)));
+    }
 }
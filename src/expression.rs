@@ -0,0 +1,481 @@
+//! Evaluating arithmetic on quantities, such as `"(3 km) / (20 min)"`.
+//!
+//! Expressions combine numbers with registered unit symbols using `+`, `-`, `*` (or `·`),
+//! `/`, parentheses and integer powers (`^2`, `²`, `^-1`). A number directly followed by
+//! a unit is a single quantity, so `"3 km / 20 min"` divides 3 km by 20 min. Powers whose
+//! dimension exponents leave the range of `i8`, as in `"(1 m^100)^2"`, are errors.
+//!
+//! Units are matched against the [`registry`](crate::registry), preferring the longest
+//! symbol (`"m/s²"` over `"m"`). A symbol shared by several measurements resolves to
+//! the one in the earliest [`UnitSystem`](crate::UnitSystem), so `"g"` is the gram.
+//!
+//! Absolute temperatures keep their [`QuantityKind`](crate::quantity::QuantityKind):
+//! `"30 °C - 20 °C"` is a temperature difference, while `"20 °C + 20 °C"` and `"2 * 0 °C"`
+//! are errors.
+//!
+//! ```
+//! use besi::prelude::*;
+//! use besi::expression::{evaluate, evaluate_as};
+//!
+//! let speed: Velocity = evaluate_as("3 km / 20 min").unwrap();
+//! assert_eq!(speed, Velocity(2.5));
+//!
+//! let force = evaluate("5 t * 9.8 m/s²").unwrap();
+//! assert_eq!(force.measurement(), Some("Force"));
+//!
+//! assert!(evaluate_as::<Length>("3 km / 20 min").is_err());
+//! ```
+
+use std::{error::Error, fmt};
+
+use crate::{
+    UnitDisplayInfo,
+    quantity::{Dimension, DimensionMismatchError, DynQuantity, QuantityError},
+};
+
+/// The error returned when an expression cannot be evaluated.
+#[derive(Debug, Clone, PartialEq)]
+pub enum EvalError {
+    /// The expression ended where a value or closing parenthesis was expected.
+    UnexpectedEnd,
+    /// An unexpected character at the given byte offset.
+    Unexpected { position: usize, found: char },
+    /// A number that cannot be parsed.
+    MalformedNumber(String),
+    /// Text that is neither a number nor a registered unit symbol.
+    UnknownUnit(String),
+    /// A power that is not a small integer.
+    InvalidExponent(String),
+    /// A power of a unit that isn't proportional to its base unit, such as `°C²` or `dBm²`.
+    NonLinearPower(String),
+    /// Quantities of different dimensions were added or subtracted, or the result
+    /// has the wrong dimension.
+    DimensionMismatch(DimensionMismatchError),
+    /// An absolute quantity of the given dimension, such as `20 °C`, was scaled, negated
+    /// or multiplied.
+    Absolute(Dimension),
+    /// An exponent of the result's dimension doesn't fit in an `i8`, as in `(1 m^100)^2`.
+    ExponentOverflow,
+}
+
+impl fmt::Display for EvalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnexpectedEnd => write!(f, "unexpected end of expression"),
+            Self::Unexpected { position, found } => {
+                write!(f, "unexpected `{found}` at position {position}")
+            }
+            Self::MalformedNumber(number) => write!(f, "malformed number `{number}`"),
+            Self::UnknownUnit(unit) => write!(f, "unknown unit `{unit}`"),
+            Self::InvalidExponent(exponent) => write!(f, "invalid exponent `{exponent}`"),
+            Self::NonLinearPower(unit) => write!(f, "the unit `{unit}` can't be raised to a power"),
+            Self::DimensionMismatch(error) => error.fmt(f),
+            Self::Absolute(dimension) => QuantityError::Absolute(*dimension).fmt(f),
+            Self::ExponentOverflow => QuantityError::ExponentOverflow.fmt(f),
+        }
+    }
+}
+
+impl Error for EvalError {}
+
+impl From<DimensionMismatchError> for EvalError {
+    fn from(error: DimensionMismatchError) -> Self {
+        Self::DimensionMismatch(error)
+    }
+}
+
+impl From<QuantityError> for EvalError {
+    fn from(error: QuantityError) -> Self {
+        match error {
+            QuantityError::Absolute(dimension) => Self::Absolute(dimension),
+            QuantityError::ExponentOverflow => Self::ExponentOverflow,
+        }
+    }
+}
+
+/// Evaluates `expression` to a quantity of whatever dimension it has.
+pub fn evaluate(expression: &str) -> Result<DynQuantity, EvalError> {
+    let mut parser = Parser {
+        input: expression,
+        position: 0,
+    };
+    let value = parser.sum()?;
+    parser.skip_whitespace();
+    match parser.peek() {
+        Some(found) => Err(EvalError::Unexpected {
+            position: parser.position,
+            found,
+        }),
+        None => Ok(value),
+    }
+}
+
+/// Evaluates `expression` to the measurement `M`, failing if the dimensions don't match.
+pub fn evaluate_as<M>(expression: &str) -> Result<M, EvalError>
+where
+    M: TryFrom<DynQuantity, Error = DimensionMismatchError>,
+{
+    Ok(M::try_from(evaluate(expression)?)?)
+}
+
+/// A parsed atom, and whether it already includes the sign before it.
+enum Atom {
+    Signed(DynQuantity),
+    Unsigned(DynQuantity),
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn rest(&self) -> &'a str {
+        &self.input[self.position..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.position += rest.len() - rest.trim_start().len();
+    }
+
+    /// Consumes `c` if it is the next character after any whitespace.
+    fn eat(&mut self, c: char) -> bool {
+        self.skip_whitespace();
+        if self.peek() == Some(c) {
+            self.position += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn unexpected(&self) -> EvalError {
+        match self.peek() {
+            Some(found) => EvalError::Unexpected {
+                position: self.position,
+                found,
+            },
+            None => EvalError::UnexpectedEnd,
+        }
+    }
+
+    /// `product (('+' | '-') product)*`
+    fn sum(&mut self) -> Result<DynQuantity, EvalError> {
+        let mut value = self.product()?;
+        loop {
+            if self.eat('+') {
+                value = value.try_add(self.product()?)?;
+            } else if self.eat('-') {
+                value = value.try_sub(self.product()?)?;
+            } else {
+                return Ok(value);
+            }
+        }
+    }
+
+    /// `unary (('*' | '·' | '/') unary)*`
+    fn product(&mut self) -> Result<DynQuantity, EvalError> {
+        let mut value = self.unary()?;
+        loop {
+            if self.eat('*') || self.eat('·') {
+                value = (value * self.unary()?)?;
+            } else if self.eat('/') {
+                value = (value / self.unary()?)?;
+            } else {
+                return Ok(value);
+            }
+        }
+    }
+
+    /// `'-'* power`
+    fn unary(&mut self) -> Result<DynQuantity, EvalError> {
+        let mut negative = false;
+        while self.eat('-') {
+            negative = !negative;
+        }
+        self.power(negative)
+    }
+
+    /// `atom exponent?`, negated if `negative`.
+    fn power(&mut self, negative: bool) -> Result<DynQuantity, EvalError> {
+        // The sign of a number with a unit belongs to the number, so "-40 °C" is 233.15 K.
+        let (value, signed) = match self.atom(negative)? {
+            Atom::Signed(value) => (value, true),
+            Atom::Unsigned(value) => (value, false),
+        };
+        let value = match self.exponent()? {
+            Some(exponent) => value.powi(exponent)?,
+            None => value,
+        };
+        Ok(if negative && !signed { (-value)? } else { value })
+    }
+
+    /// `'(' sum ')' | number unit? | unit`, where a unit takes the sign if `negative`.
+    fn atom(&mut self, negative: bool) -> Result<Atom, EvalError> {
+        self.skip_whitespace();
+        if self.eat('(') {
+            let value = self.sum()?;
+            if !self.eat(')') {
+                return Err(self.unexpected());
+            }
+            return Ok(Atom::Unsigned(value));
+        }
+
+        let number = self.number()?;
+        self.skip_whitespace();
+        match (number, self.unit()) {
+            (number, Some(unit)) => {
+                let number = number.unwrap_or(1.0) * if negative { -1.0 } else { 1.0 };
+                // A power applies to the unit only, as in "3 m²", so it must scale linearly.
+                match self.exponent()? {
+                    Some(_) if unit.offset != 0.0 || !unit.conversion.is_linear() => {
+                        Err(EvalError::NonLinearPower(unit.symbol.to_string()))
+                    }
+                    Some(exponent) => Ok(Atom::Signed(
+                        (DynQuantity::new(unit.scale_factor, unit.dimension).powi(exponent)? * number)?,
+                    )),
+                    None => Ok(Atom::Signed(
+                        DynQuantity::new(unit.to_base(number), unit.dimension).with_kind(unit.kind),
+                    )),
+                }
+            }
+            (number, None) => match self.peek() {
+                Some(c) if c.is_alphabetic() => {
+                    let end = self
+                        .rest()
+                        .find(|c: char| !c.is_alphanumeric())
+                        .unwrap_or(self.rest().len());
+                    Err(EvalError::UnknownUnit(self.rest()[..end].to_string()))
+                }
+                _ => number
+                    .map(|number| Atom::Unsigned(number.into()))
+                    .ok_or_else(|| self.unexpected()),
+            },
+        }
+    }
+
+    /// An optional unsigned number such as `3`, `.5` or `6.02e23`.
+    fn number(&mut self) -> Result<Option<f64>, EvalError> {
+        let bytes = self.rest().as_bytes();
+        let digits = |mut i: usize| {
+            while i < bytes.len() && bytes[i].is_ascii_digit() {
+                i += 1;
+            }
+            i
+        };
+
+        let mut end = digits(0);
+        if bytes.get(end) == Some(&b'.') {
+            end = digits(end + 1);
+        }
+        if end == 0 {
+            return Ok(None);
+        }
+        if matches!(bytes.get(end), Some(b'e' | b'E')) {
+            let mut exponent = end + 1;
+            if matches!(bytes.get(exponent), Some(b'+' | b'-')) {
+                exponent += 1;
+            }
+            if bytes.get(exponent).is_some_and(u8::is_ascii_digit) {
+                end = digits(exponent);
+            }
+        }
+
+        let text = &self.rest()[..end];
+        let number = text
+            .parse()
+            .map_err(|_| EvalError::MalformedNumber(text.to_string()))?;
+        self.position += end;
+        Ok(Some(number))
+    }
+
+    /// The registered unit with the longest symbol at the current position.
+    ///
+    /// A symbol ending in a letter must not be followed by another letter, so `"mi"`
+    /// isn't read as meters followed by `"i"`.
+    fn unit(&mut self) -> Option<&'static UnitDisplayInfo> {
+        let rest = self.rest();
+        let ends_word = |symbol: &str| {
+            let letter = |c: Option<char>| c.is_some_and(char::is_alphabetic);
+            !(letter(symbol.chars().last()) && letter(rest[symbol.len()..].chars().next()))
+        };
+        let unit = inventory::iter::<UnitDisplayInfo>
+            .into_iter()
+            .filter(|unit| rest.starts_with(unit.symbol) && ends_word(unit.symbol))
+            .max_by(|a, b| {
+                a.symbol
+                    .len()
+                    .cmp(&b.symbol.len())
+                    .then(b.system.cmp(&a.system))
+                    .then(b.measurement_type_name.cmp(a.measurement_type_name))
+            })?;
+        self.position += unit.symbol.len();
+        Some(unit)
+    }
+
+    /// An optional power: `'^' '-'? digits` or superscript digits such as `²` or `⁻¹`.
+    fn exponent(&mut self) -> Result<Option<i8>, EvalError> {
+        const SUPERSCRIPTS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+
+        let start = self.position;
+        let text: String = if self.eat('^') {
+            self.skip_whitespace();
+            let rest = self.rest();
+            let sign = usize::from(rest.starts_with('-'));
+            let end = rest[sign..]
+                .find(|c: char| !c.is_ascii_digit())
+                .map_or(rest.len(), |end| end + sign);
+            self.position += end;
+            rest[..end].to_string()
+        } else {
+            self.position = start;
+            let rest = self.rest();
+            let end = rest
+                .find(|c: char| c != '⁻' && !SUPERSCRIPTS.contains(&c))
+                .unwrap_or(rest.len());
+            if end == 0 {
+                return Ok(None);
+            }
+            self.position += end;
+            rest[..end]
+                .chars()
+                .map(|c| {
+                    SUPERSCRIPTS
+                        .iter()
+                        .position(|digit| *digit == c)
+                        .map_or('-', |digit| char::from(b'0' + digit as u8))
+                })
+                .collect()
+        };
+
+        text.parse()
+            .map(Some)
+            .map_err(|_| EvalError::InvalidExponent(text))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn evaluates_arithmetic() {
+        assert_eq!(evaluate_as::<Length>("1 km + 500 m"), Ok(Length(1500.0)));
+        assert_eq!(
+            evaluate_as::<Length>("2 * (1 km - 500 m)"),
+            Ok(Length(1000.0))
+        );
+        assert_eq!(
+            evaluate_as::<Velocity>("(3 km) / (20 min)"),
+            Ok(Velocity(2.5))
+        );
+        assert_eq!(evaluate_as::<f64>("1 km / 1 m"), Ok(1000.0));
+        assert_eq!(evaluate_as::<f64>("-2^2 + 1"), Ok(-3.0));
+        assert_eq!(evaluate_as::<Volume>("(2 m)^3"), Ok(Volume(8.0)));
+        assert_eq!(evaluate_as::<Volume>("2 m³"), Ok(Volume(2.0)));
+        assert_eq!(
+            evaluate_as::<Acceleration>("10 m / s²"),
+            Ok(Acceleration(10.0))
+        );
+        assert_eq!(evaluate_as::<Mass>("5 g"), Ok(Mass(0.005)));
+    }
+
+    #[test]
+    fn matches_cross_type_operators() {
+        let length: Length = evaluate_as("20 m/s * 1 min").unwrap();
+        assert_eq!(length, Velocity(20.0) * Time(60.0));
+
+        let force: Force = evaluate_as("5 t * 9.8 m/s²").unwrap();
+        assert_eq!(force, Acceleration(9.8) * Mass(5000.0));
+    }
+
+    #[test]
+    fn tells_temperatures_from_differences() {
+        let error = evaluate("20 °C + 20 °C").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "kind mismatch: expected TemperatureDifference (K), found Temperature (K)"
+        );
+        assert!(evaluate_as::<Temperature>("20 °C + 20 °C").is_err());
+        assert!(evaluate("5 Δ°C - 20 °C").is_err());
+        assert_eq!(
+            evaluate("2 * 0 °C"),
+            Err(EvalError::Absolute(Temperature::DIMENSION))
+        );
+        assert_eq!(
+            evaluate("-(20 °C)").unwrap_err().to_string(),
+            "an absolute Temperature (K) can't be scaled, negated or multiplied"
+        );
+        assert!(evaluate("20 °C / 2").is_err());
+        assert!(evaluate("(30 °C - 20 °C) * 2").is_ok());
+
+        let difference = evaluate("30 °C - 20 °C").unwrap();
+        assert_eq!(difference.measurement(), Some("TemperatureDifference"));
+        assert!(evaluate_as::<Temperature>("30 °C - 20 °C").is_err());
+        let difference: TemperatureDifference = evaluate_as("30 °C - 20 °C").unwrap();
+        assert!((difference.0 - 10.0).abs() < 1e-9);
+
+        let warmer: Temperature = evaluate_as("20 °C + 5 Δ°C").unwrap();
+        assert!((warmer.get::<degree_celsius>() - 25.0).abs() < 1e-9);
+        let cold: Temperature = evaluate_as("-40 °C").unwrap();
+        assert!((cold.get::<degree_fahrenheit>() + 40.0).abs() < 1e-9);
+        let ratio: f64 = evaluate_as("-10 dBm / 1 mW").unwrap();
+        assert!((ratio - 0.1).abs() < 1e-9);
+    }
+
+    #[test]
+    fn reports_errors() {
+        assert_eq!(evaluate("1 km +"), Err(EvalError::UnexpectedEnd));
+        assert_eq!(
+            evaluate("1 furlong"),
+            Err(EvalError::UnknownUnit("furlong".to_string()))
+        );
+        assert_eq!(evaluate("(1 km"), Err(EvalError::UnexpectedEnd));
+        assert_eq!(
+            evaluate("1 km ) 2"),
+            Err(EvalError::Unexpected {
+                position: 5,
+                found: ')'
+            })
+        );
+        assert_eq!(
+            evaluate("1 m ^ x"),
+            Err(EvalError::InvalidExponent(String::new()))
+        );
+        assert_eq!(evaluate("(1 m^100)^2"), Err(EvalError::ExponentOverflow));
+        assert_eq!(evaluate("1 m^127 * 1 m^127"), Err(EvalError::ExponentOverflow));
+        assert_eq!(evaluate("1 m³^100"), Err(EvalError::ExponentOverflow));
+        assert_eq!(
+            evaluate("1 / 1 m^-128").unwrap_err().to_string(),
+            "dimension exponent out of range"
+        );
+        assert_eq!(
+            evaluate("3 °C^1"),
+            Err(EvalError::NonLinearPower("°C".to_string()))
+        );
+        assert_eq!(
+            evaluate("°F²"),
+            Err(EvalError::NonLinearPower("°F".to_string()))
+        );
+        assert_eq!(
+            evaluate("2 dBm^2").unwrap_err().to_string(),
+            "the unit `dBm` can't be raised to a power"
+        );
+
+        let error = evaluate("1 km + 1 s").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "dimension mismatch: expected Length (m), found Time (s)"
+        );
+        assert!(matches!(
+            evaluate_as::<Force>("1 km / 1 s"),
+            Err(EvalError::DimensionMismatch(_))
+        ));
+    }
+}
@@ -2,12 +2,26 @@ use crate::{define_measurement, define_units};
 
 define_measurement! {
     /// A measurement of flow, with a base unit of cubic meters per second.
-    VolumeRate
+    VolumeRate = [length: 3, time: -1]
 }
 
 define_units! { VolumeRate =>
-    cubic_meters_per_second: ("m³/s", 1.0),
-    liter_per_second: ("L/s", 0.001),
+    cubic_meters_per_second: ("m³/s", 1.0, Si, "cubic meter per second", "cubic meters per second"),
+    liter_per_second: ("L/s", 0.001, Metric, "liter per second", "liters per second"),
+}
+
+define_measurement! {
+    /// A measurement of mass flow, with a base unit of kilograms per second.
+    MassFlowRate = [mass: 1, time: -1]
+}
+
+define_units! { MassFlowRate =>
+    grams_per_second: ("g/s", 0.001, Si, "gram per second", "grams per second"),
+    #[prefix(KILO)]
+    kilograms_per_second: ("kg/s", 1.0, Si, "kilogram per second", "kilograms per second"),
+    #[prefix(KILO)]
+    kilograms_per_hour: ("kg/h", 1.0 / 3600.0, Metric, "kilogram per hour", "kilograms per hour"),
+    tonnes_per_hour: ("t/h", 1.0 / 3.6, Metric, "tonne per hour", "tonnes per hour"),
 }
 
 #[cfg(test)]
@@ -19,4 +33,11 @@ mod tests {
         let flow = VolumeRate::new::<liter_per_second>(1000.0);
         assert_eq!(flow.as_ref(), &1.0);
     }
+
+    #[test]
+    fn mass_flow() {
+        let flow = MassFlowRate::new::<tonnes_per_hour>(36.0);
+        assert!((flow.get::<kilograms_per_second>() - 10.0).abs() < 1e-12);
+        assert_eq!(MassFlowRate(2.5).to_string(), "2.50 kg/s");
+    }
 }
@@ -1,28 +1,26 @@
-use std::ops::Mul;
-
-use crate::{define_measurement, define_units, mass::Mass, prelude::Acceleration};
+use crate::{define_measurement, define_units, energy::Energy, length::Length, torque::Torque};
 
 define_measurement! {
     /// A measurement of force, with a base unit of newtons.
-    Force
+    Force = [mass: 1, length: 1, time: -2]
 }
 
 define_units! { Force =>
-    newton: ("N", 1.0),
-    kilonewton: ("kN", 1000.0),
+    #[si_prefixes]
+    newton: ("N", 1.0, Si, "newton", "newtons"),
 }
 
-impl Mul<Mass> for Acceleration {
-    type Output = Force;
-    fn mul(self, rhs: Mass) -> Force {
-        Force(self.as_ref() * rhs.as_ref())
+// Work and moment are both newton meters, so `Force * Length` is deliberately not an
+// operator: the two are told apart by name.
+impl Force {
+    /// The work done by this force along `distance`, in the direction of the force.
+    pub fn work(self, distance: Length) -> Energy {
+        Energy(self.0 * distance.0)
     }
-}
 
-impl Mul<Acceleration> for Mass {
-    type Output = Force;
-    fn mul(self, rhs: Acceleration) -> Force {
-        Force(self.as_ref() * rhs.as_ref())
+    /// The moment of this force at the perpendicular lever arm `arm`.
+    pub fn moment(self, arm: Length) -> Torque {
+        Torque(self.0 * arm.0)
     }
 }
 
@@ -35,4 +33,11 @@ mod tests {
         let force = Force::new::<kilonewton>(1.0);
         assert_eq!(force.as_ref(), &1000.0);
     }
+
+    #[test]
+    fn work_and_moment() {
+        let force = Force::new::<newton>(50.0);
+        assert_eq!(force.work(Length(3.0)), Energy(150.0));
+        assert_eq!(force.moment(Length(0.4)), Torque(20.0));
+    }
 }
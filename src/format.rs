@@ -0,0 +1,468 @@
+//! Helpers for formatting measurements.
+
+use std::fmt::{self, Alignment, Formatter};
+
+use crate::{Measurement, UnitDisplayInfo, UnitSystem, display::DisplayPolicy, prefix::SiPrefix};
+
+/// The number of decimals used when the formatter has no precision.
+pub const DEFAULT_PRECISION: usize = 2;
+
+/// Formats `value` with the formatter's precision (or [`DEFAULT_PRECISION`]) and `+` flag.
+pub fn number(f: &Formatter<'_>, value: f64) -> String {
+    let precision = f.precision().unwrap_or(DEFAULT_PRECISION);
+    if f.sign_plus() {
+        format!("{value:+.precision$}")
+    } else {
+        format!("{value:.precision$}")
+    }
+}
+
+/// Writes `value` followed by its unit symbol, honouring the formatter's
+/// precision, `+` flag, width, fill, alignment and `0` flag.
+pub fn write_value(f: &mut Formatter<'_>, value: f64, unit: &str) -> fmt::Result {
+    let number = number(f, value);
+    pad(f, &format!("{number} {unit}"))
+}
+
+/// Writes `value` in `unit`, using the long unit names in alternate mode (`{:#}`).
+pub fn write_unit(f: &mut Formatter<'_>, value: f64, unit: &UnitDisplayInfo) -> fmt::Result {
+    if f.alternate() {
+        write_long(f, value, unit.singular, unit.plural)
+    } else {
+        write_value(f, value, unit.symbol)
+    }
+}
+
+/// Writes `value` followed by the singular or plural long name of its unit, as in
+/// `"1.5 kilometers"` or `"1 solar mass"`.
+///
+/// Without an explicit precision, trailing zeros are dropped from the default two decimals.
+pub fn write_long(f: &mut Formatter<'_>, value: f64, singular: &str, plural: &str) -> fmt::Result {
+    let mut number = number(f, value);
+    if f.precision().is_none() && number.contains('.') {
+        number.truncate(number.trim_end_matches('0').trim_end_matches('.').len());
+    }
+    pad(
+        f,
+        &format!("{number} {}", long_name(&number, singular, plural)),
+    )
+}
+
+/// Picks the singular name for a formatted number of exactly one, the plural otherwise.
+fn long_name<'a>(number: &str, singular: &'a str, plural: &'a str) -> &'a str {
+    if number.trim_start_matches(['+', '-']) == "1" {
+        singular
+    } else {
+        plural
+    }
+}
+
+/// Writes `text` padded to the formatter's width.
+///
+/// Unlike [`Formatter::pad`], this never truncates `text` to the precision, which
+/// measurements use for their decimals. Text is right-aligned by default, like numbers.
+pub fn pad(f: &mut Formatter<'_>, text: &str) -> fmt::Result {
+    let len = text.chars().count();
+    let padding = match f.width() {
+        Some(width) if width > len => width - len,
+        _ => return f.write_str(text),
+    };
+
+    if f.sign_aware_zero_pad() {
+        let sign_len = if text.starts_with(['+', '-']) { 1 } else { 0 };
+        let (sign, rest) = text.split_at(sign_len);
+        f.write_str(sign)?;
+        for _ in 0..padding {
+            f.write_str("0")?;
+        }
+        return f.write_str(rest);
+    }
+
+    let (before, after) = match f.align() {
+        Some(Alignment::Left) => (0, padding),
+        Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+        Some(Alignment::Right) | None => (padding, 0),
+    };
+    let fill = f.fill();
+    for _ in 0..before {
+        write!(f, "{fill}")?;
+    }
+    f.write_str(text)?;
+    for _ in 0..after {
+        write!(f, "{fill}")?;
+    }
+    Ok(())
+}
+
+/// How numbers are written by [`FormatOptions`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Notation {
+    /// Fixed decimals in the unit chosen by the display policy, e.g. `"1.50 km"`.
+    #[default]
+    Fixed,
+    /// Scientific notation in the base unit, e.g. `"3.85e26 W"`.
+    Scientific,
+    /// Engineering notation with an SI prefix on the base unit, e.g. `"384.60 YW"`.
+    ///
+    /// Base units that can't take a prefix (such as `m³` or `kg/m³`) use an exponent
+    /// that is a multiple of three instead, e.g. `"1.50e3 kg/m³"`.
+    Engineering,
+    /// The given number of significant figures in the unit chosen by the display policy.
+    SignificantFigures(usize),
+}
+
+/// Options for the `display_as` method of every measurement.
+///
+/// The formatter's precision sets the number of decimals (two by default) for every
+/// notation but [`Notation::SignificantFigures`], and `{:#}` spells units out.
+///
+/// ```
+/// use besi::prelude::*;
+///
+/// let sun = Power::new::<solar_luminosity>(1.0);
+/// assert_eq!(sun.display_as(&FormatOptions::scientific()).to_string(), "3.85e26 W");
+/// assert_eq!(format!("{:.1}", sun.display_as(&FormatOptions::engineering())), "384.6 YW");
+/// assert_eq!(sun.display_as(&FormatOptions::significant_figures(4)).to_string(), "1.000 L☉");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FormatOptions {
+    /// How the number is written.
+    pub notation: Notation,
+    /// The policy used to pick a unit, or the global policy if `None`.
+    pub policy: Option<DisplayPolicy>,
+}
+
+impl FormatOptions {
+    /// Scientific notation in the base unit.
+    pub fn scientific() -> Self {
+        Self::new(Notation::Scientific)
+    }
+
+    /// Engineering notation with SI prefixes.
+    pub fn engineering() -> Self {
+        Self::new(Notation::Engineering)
+    }
+
+    /// `figures` significant figures in the display unit.
+    pub fn significant_figures(figures: usize) -> Self {
+        Self::new(Notation::SignificantFigures(figures.max(1)))
+    }
+
+    /// Options with the given notation and the global display policy.
+    pub fn new(notation: Notation) -> Self {
+        Self {
+            notation,
+            policy: None,
+        }
+    }
+
+    /// Uses `policy` to pick the display unit.
+    pub fn with_policy(mut self, policy: DisplayPolicy) -> Self {
+        self.policy = Some(policy);
+        self
+    }
+
+    /// Writes `value` (in base units) of `measurement`.
+    pub fn write(&self, f: &mut Formatter<'_>, measurement: &str, value: f64) -> fmt::Result {
+        match self.notation {
+            Notation::Fixed | Notation::SignificantFigures(_) => {
+                let unit = match &self.policy {
+                    Some(policy) => policy.select(measurement, value),
+                    None => DisplayPolicy::select_global(measurement, value),
+                };
+                self.write_in(f, unit.from_base(value), unit)
+            }
+            Notation::Scientific | Notation::Engineering => {
+                // Explicit base units are only used by measurements without another one.
+                let base = UnitDisplayInfo::units_of(measurement)
+                    .filter(|unit| unit.is_base())
+                    .min_by_key(|unit| (unit.explicit, unit.system))
+                    .unwrap_or_else(|| UnitDisplayInfo::best_for(measurement, value));
+                // Prefixes go on the unprefixed unit, so kilograms are written in grams.
+                let root = base
+                    .prefix
+                    .filter(|_| self.notation == Notation::Engineering)
+                    .and_then(|prefix| {
+                        let symbol = base.symbol.strip_prefix(prefix.symbol)?;
+                        UnitDisplayInfo::units_of(measurement)
+                            .find(|unit| unit.prefix.is_none() && unit.symbol == symbol)
+                    })
+                    .unwrap_or(base);
+                self.write_base(f, root.from_base(value), root)
+            }
+        }
+    }
+
+    /// Writes `value`, already in the display `unit`, for the fixed notations.
+    fn write_in(&self, f: &mut Formatter<'_>, value: f64, unit: &UnitDisplayInfo) -> fmt::Result {
+        match self.notation {
+            // Infinities and NaN have no magnitude to round to.
+            Notation::SignificantFigures(_) if !value.is_finite() => write_unit(f, value, unit),
+            Notation::SignificantFigures(figures) => {
+                let number = significant_figures(value, figures, f.sign_plus());
+                write_named(f, &number, unit, "")
+            }
+            _ if f.alternate() => write_long(f, value, unit.singular, unit.plural),
+            _ => write_value(f, value, unit.symbol),
+        }
+    }
+
+    /// Writes `value`, already in the base (or root) `unit`, for the exponent notations.
+    fn write_base(&self, f: &mut Formatter<'_>, value: f64, unit: &UnitDisplayInfo) -> fmt::Result {
+        if !value.is_finite() {
+            return write_unit(f, value, unit);
+        }
+        let precision = f.precision().unwrap_or(DEFAULT_PRECISION);
+        let plus = if f.sign_plus() { "+" } else { "" };
+        if self.notation == Notation::Scientific {
+            let number = format!(
+                "{plus}{}",
+                format!("{value:.precision$e}").replace("e0", "")
+            );
+            return write_named(f, &number, unit, "");
+        }
+
+        let mut exponent = if value == 0.0 {
+            0
+        } else {
+            (value.abs().log10() / 3.0).floor() as i32 * 3
+        };
+        // Rounding the mantissa may carry it into the next group of three.
+        if format!("{:.precision$}", (value / 10f64.powi(exponent)).abs()).starts_with("1000") {
+            exponent += 3;
+        }
+
+        let mantissa = value / 10f64.powi(exponent);
+        let number = format!("{mantissa:+.precision$}");
+        let number = number
+            .strip_prefix('+')
+            .map_or(number.clone(), |n| format!("{plus}{n}"));
+        let prefix = SiPrefix::from_exponent(exponent)
+            .filter(|_| unit.prefix.is_none() && takes_prefix(unit));
+        match prefix {
+            Some(prefix) => {
+                let prefix = if f.alternate() { prefix.name } else { prefix.symbol };
+                write_named(f, &number, unit, prefix)
+            }
+            None if exponent == 0 => write_named(f, &number, unit, ""),
+            None => write_named(f, &format!("{number}e{exponent}"), unit, ""),
+        }
+    }
+}
+
+/// Whether an SI prefix can be put in front of `unit` without changing its meaning:
+/// `"m/s"` becomes `"km/s"`, but `"m³"` can't become `"km³"`. Only SI units take
+/// prefixes, and differences such as `"ΔK"` don't either.
+fn takes_prefix(unit: &UnitDisplayInfo) -> bool {
+    let symbol = unit.symbol;
+    let first = symbol
+        .find(|c: char| !c.is_alphabetic() || c == '²' || c == '³')
+        .map_or(symbol, |end| &symbol[..end]);
+    unit.system == UnitSystem::Si
+        && !first.is_empty()
+        && !first.starts_with('Δ')
+        && !symbol[first.len()..].starts_with(['²', '³'])
+}
+
+/// Formats `value` with `figures` significant figures.
+fn significant_figures(value: f64, figures: usize, plus: bool) -> String {
+    let mut magnitude = if value == 0.0 {
+        0
+    } else {
+        value.abs().log10().floor() as i32
+    };
+    // Rounding may carry into the next power of ten, as 9.996 does to 10.0.
+    let scale = 10f64.powi(magnitude + 1 - figures as i32);
+    if ((value / scale).round() * scale).abs() >= 10f64.powi(magnitude + 1) {
+        magnitude += 1;
+    }
+    let decimals = figures as i32 - 1 - magnitude;
+    let text = if decimals >= 0 {
+        format!("{value:.0$}", decimals as usize)
+    } else {
+        let scale = 10f64.powi(-decimals);
+        format!("{:.0}", (value / scale).round() * scale)
+    };
+    if plus && !text.starts_with('-') {
+        format!("+{text}")
+    } else {
+        text
+    }
+}
+
+/// Writes `number` followed by `prefix` and the unit's symbol, or its long name in
+/// alternate mode (where `prefix` is the long prefix name).
+fn write_named(
+    f: &mut Formatter<'_>,
+    number: &str,
+    unit: &UnitDisplayInfo,
+    prefix: &str,
+) -> fmt::Result {
+    let name = if f.alternate() {
+        long_name(number, unit.singular, unit.plural)
+    } else {
+        unit.symbol
+    };
+    pad(f, &format!("{number} {prefix}{name}"))
+}
+
+/// Displays a measurement according to [`FormatOptions`].
+///
+/// Created by the `display_as` method of every measurement.
+pub struct OptionsDisplay<'a, M> {
+    value: M,
+    options: &'a FormatOptions,
+}
+
+impl<'a, M: Measurement> OptionsDisplay<'a, M> {
+    pub fn new(value: M, options: &'a FormatOptions) -> Self {
+        Self { value, options }
+    }
+}
+
+impl<M: Measurement> fmt::Display for OptionsDisplay<'_, M> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.options.write(f, M::NAME, self.value.to_base())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn precision_and_sign() {
+        assert_eq!(format!("{}", Length(1500.0)), "1.50 km");
+        assert_eq!(format!("{:.5}", Length(1234.5)), "1.23450 km");
+        assert_eq!(format!("{:.0}", Length(1500.0)), "2 km");
+        assert_eq!(format!("{:+}", Length(1500.0)), "+1.50 km");
+        assert_eq!(format!("{:+.1}", Length(-1500.0)), "-1.5 km");
+    }
+
+    #[test]
+    fn width_alignment_and_fill() {
+        assert_eq!(format!("{:10}", Length(1500.0)), "   1.50 km");
+        assert_eq!(format!("{:>10}", Length(1500.0)), "   1.50 km");
+        assert_eq!(format!("{:<10}|", Length(1500.0)), "1.50 km   |");
+        assert_eq!(format!("{:*^11}", Length(1500.0)), "**1.50 km**");
+        assert_eq!(format!("{:010.1}", Length(-1500.0)), "-0001.5 km");
+        assert_eq!(format!("{:4}", Length(1500.0)), "1.50 km");
+    }
+
+    #[cfg(feature = "position")]
+    #[test]
+    fn position_forwards_flags() {
+        let pos = crate::position::DPos3::new::<meter>(1500.0, -2.0, 0.5);
+        assert_eq!(format!("{:.1}", pos), "(1.5 km, -2.0 m, 50.0 cm)");
+        assert_eq!(format!("{:>30}", pos), "  (1.50 km, -2.00 m, 50.00 cm)");
+    }
+
+    #[test]
+    fn long_names() {
+        assert_eq!(format!("{:#}", Length(1500.0)), "1.5 kilometers");
+        assert_eq!(
+            format!("{:#}", Mass::new::<solar_mass>(1.0)),
+            "1 solar mass"
+        );
+        assert_eq!(format!("{:#}", Length(-1.0)), "-1 meter");
+        assert_eq!(format!("{:#.2}", Length(1000.0)), "1.00 kilometers");
+        assert_eq!(format!("{:#}", Time(7200.0)), "2 hours");
+        assert_eq!(format!("{:#}", Velocity(0.5)), "1.8 kilometers per hour");
+    }
+
+    #[test]
+    fn scientific() {
+        let options = FormatOptions::scientific();
+        assert_eq!(Length(1500.0).display_as(&options).to_string(), "1.50e3 m");
+        assert_eq!(
+            format!("{:.3}", Length(-0.00123).display_as(&options)),
+            "-1.230e-3 m"
+        );
+        assert_eq!(
+            format!("{:+.1}", Length(1.0).display_as(&options)),
+            "+1.0 m"
+        );
+        assert_eq!(Mass(2.0).display_as(&options).to_string(), "2.00 kg");
+    }
+
+    #[test]
+    fn engineering() {
+        let options = FormatOptions::engineering();
+        assert_eq!(Length(1500.0).display_as(&options).to_string(), "1.50 km");
+        assert_eq!(
+            Length(0.000_25).display_as(&options).to_string(),
+            "250.00 µm"
+        );
+        assert_eq!(
+            Length(999_999.0).display_as(&options).to_string(),
+            "1.00 Mm"
+        );
+        assert_eq!(
+            Mass::new::<tonne>(5.0).display_as(&options).to_string(),
+            "5.00 Mg"
+        );
+        assert_eq!(Mass(0.0025).display_as(&options).to_string(), "2.50 g");
+        assert_eq!(
+            MassFlowRate(1500.0).display_as(&options).to_string(),
+            "1.50 Mg/s"
+        );
+        assert_eq!(Volume(1500.0).display_as(&options).to_string(), "1.50e3 m³");
+        assert_eq!(
+            MassDensity(1500.0).display_as(&options).to_string(),
+            "1.50e3 kg/m³"
+        );
+        assert_eq!(
+            format!("{:#.1}", Power(2.5e6).display_as(&options)),
+            "2.5 megawatts"
+        );
+    }
+
+    #[test]
+    fn significant_figures() {
+        let options = FormatOptions::significant_figures(3);
+        assert_eq!(Length(1234.5).display_as(&options).to_string(), "1.23 km");
+        assert_eq!(Length(123_456.0).display_as(&options).to_string(), "123 km");
+        assert_eq!(
+            Length::new::<astronomical_unit>(12345.0)
+                .display_as(&options)
+                .to_string(),
+            "12300 AU"
+        );
+        assert_eq!(Length(0.5).display_as(&options).to_string(), "50.0 cm");
+        assert_eq!(Length(9.996).display_as(&options).to_string(), "10.0 m");
+        assert_eq!(Length(-99.96).display_as(&options).to_string(), "-100 m");
+        assert_eq!(
+            format!(
+                "{:#}",
+                Mass::new::<solar_mass>(1.0).display_as(&FormatOptions::significant_figures(1))
+            ),
+            "1 solar mass"
+        );
+    }
+
+    #[test]
+    fn non_finite_values() {
+        let notations = [
+            FormatOptions::scientific(),
+            FormatOptions::engineering(),
+            FormatOptions::significant_figures(3),
+        ];
+        for options in &notations {
+            let infinity = Length(f64::INFINITY).display_as(options).to_string();
+            assert!(infinity.starts_with("inf "), "{infinity}");
+            let negative = Length(f64::NEG_INFINITY).display_as(options).to_string();
+            assert!(negative.starts_with("-inf "), "{negative}");
+            let nan = Length(f64::NAN).display_as(options).to_string();
+            assert!(nan.starts_with("NaN "), "{nan}");
+        }
+    }
+
+    #[test]
+    fn differences_take_no_prefix() {
+        let options = FormatOptions::engineering();
+        assert_eq!(
+            TemperatureDifference(1500.0).display_as(&options).to_string(),
+            "1.50e3 ΔK"
+        );
+    }
+}
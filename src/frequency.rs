@@ -0,0 +1,67 @@
+use std::f64::consts::TAU;
+
+use crate::{define_measurement, define_units, time::Time, velocity::AngularVelocity};
+
+define_measurement! {
+    /// A measurement of frequency, with a base unit of hertz.
+    Frequency = [time: -1]
+}
+
+define_units! { Frequency =>
+    #[si_prefixes]
+    hertz: ("Hz", 1.0, Si, "hertz", "hertz"),
+    #[explicit]
+    cycles_per_minute: ("cpm", 1.0 / 60.0, Other, "cycle per minute", "cycles per minute"),
+}
+
+impl Frequency {
+    /// The period of one cycle.
+    pub fn recip(self) -> Time {
+        1.0 / self
+    }
+}
+
+/// One cycle per second is a turn of 2π radians per second.
+impl From<Frequency> for AngularVelocity {
+    fn from(frequency: Frequency) -> Self {
+        Self(frequency.0 * TAU)
+    }
+}
+
+/// A turn of 2π radians per second is one cycle per second.
+impl From<AngularVelocity> for Frequency {
+    fn from(angular_velocity: AngularVelocity) -> Self {
+        Self(angular_velocity.0 / TAU)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn it_works() {
+        let frequency = Frequency::new::<kilohertz>(2.5);
+        assert_eq!(frequency.as_ref(), &2500.0);
+        assert_eq!(frequency.to_string(), "2.50 kHz");
+        assert_eq!(Frequency::new::<cycles_per_minute>(3000.0), Frequency(50.0));
+    }
+
+    #[test]
+    fn periods() {
+        let tick_rate = Frequency::new::<hertz>(60.0);
+        assert!((tick_rate.recip().get::<millisecond>() - 16.666_666).abs() < 1e-6);
+        assert_eq!(Time(0.5).recip(), Frequency(2.0));
+        assert_eq!(tick_rate.recip().recip(), tick_rate);
+    }
+
+    #[test]
+    fn angular_velocities() {
+        let rotor = Frequency::new::<cycles_per_minute>(1200.0);
+        let angular_velocity = AngularVelocity::from(rotor);
+        assert!((angular_velocity.get::<revolution_per_minute>() - 1200.0).abs() < 1e-9);
+        assert!((angular_velocity.0 - 40.0 * std::f64::consts::PI).abs() < 1e-9);
+        assert_eq!(Frequency::from(angular_velocity), Frequency(20.0));
+    }
+}
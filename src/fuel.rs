@@ -0,0 +1,79 @@
+use crate::{area::Area, define_measurement, define_units};
+
+define_measurement! {
+    /// A measurement of fuel consumption, with a base unit of cubic meters per meter.
+    ///
+    /// A volume of fuel per distance has the dimension of an area, but is kept apart from it.
+    FuelConsumption = [length: 2], distinct_from: Area
+}
+
+define_units! { FuelConsumption =>
+    #[explicit]
+    cubic_meter_per_meter: ("m³/m", 1.0, Si, "cubic meter per meter", "cubic meters per meter"),
+    liters_per_100_kilometers: ("L/100 km", 1e-8, Metric, "liter per 100 kilometers", "liters per 100 kilometers"),
+    #[reciprocal]
+    kilometers_per_liter: ("km/L", 1e-6, Metric, "kilometer per liter", "kilometers per liter"),
+    #[reciprocal]
+    miles_per_gallon: ("mpg", 3.785411784e-3 / 1609.344, Imperial, "mile per gallon", "miles per gallon"),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn reciprocal_units() {
+        let consumption = FuelConsumption::new::<liters_per_100_kilometers>(5.0);
+        assert!((consumption.get::<kilometers_per_liter>() - 20.0).abs() < 1e-9);
+        assert!((consumption.get::<miles_per_gallon>() - 47.04).abs() < 0.01);
+
+        let economy = FuelConsumption::new::<kilometers_per_liter>(25.0);
+        assert!((economy.get::<liters_per_100_kilometers>() - 4.0).abs() < 1e-9);
+        assert!(economy > FuelConsumption::new::<kilometers_per_liter>(50.0));
+    }
+
+    #[test]
+    fn display_and_parse() {
+        let consumption: FuelConsumption = "40 mpg".parse().unwrap();
+        assert_eq!(consumption.to_string(), "5.88 L/100 km");
+
+        let policy = DisplayPolicy::default().fix::<kilometers_per_liter>();
+        assert_eq!(consumption.display_with(&policy).to_string(), "17.01 km/L");
+        assert_eq!(
+            FuelConsumption::parse("6.5 L/100 km"),
+            Ok(FuelConsumption::new::<liters_per_100_kilometers>(6.5))
+        );
+    }
+
+    #[test]
+    fn exponent_notations() {
+        let consumption = FuelConsumption::new::<liters_per_100_kilometers>(5.0);
+        assert_eq!(
+            consumption.display_as(&FormatOptions::scientific()).to_string(),
+            "5.00e-8 m³/m"
+        );
+        assert_eq!(
+            consumption.display_as(&FormatOptions::engineering()).to_string(),
+            "50.00e-9 m³/m"
+        );
+        assert_eq!(consumption.to_string(), "5.00 L/100 km");
+    }
+
+    #[test]
+    fn kept_apart_from_area() {
+        let consumption = DynQuantity::of(FuelConsumption::new::<liters_per_100_kilometers>(5.0));
+        assert_eq!(consumption.measurement(), Some("FuelConsumption"));
+        assert_eq!(consumption.to_string(), "5.00 L/100 km");
+        assert!(consumption.to::<Area>().is_err());
+        assert!((consumption + DynQuantity::of(Area(1e-8))).is_err());
+
+        let doubled = (consumption * DynQuantity::from(2.0)).unwrap();
+        let doubled = FuelConsumption::try_from(doubled).unwrap();
+        assert!((doubled.get::<liters_per_100_kilometers>() - 10.0).abs() < 1e-9);
+
+        let area = crate::expression::evaluate("5 L / 100 km").unwrap();
+        assert_eq!(area.measurement(), Some("Area"));
+        assert!(FuelConsumption::try_from(area).is_err());
+    }
+}
@@ -0,0 +1,23 @@
+use crate::{define_measurement, define_units};
+
+define_measurement! {
+    /// A measurement of inductance, with a base unit of henries.
+    Inductance = [mass: 1, length: 2, time: -2, current: -2]
+}
+
+define_units! { Inductance =>
+    #[si_prefixes]
+    henry: ("H", 1.0, Si, "henry", "henries"),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_works() {
+        let inductance = Inductance::new::<millihenry>(10.0);
+        assert!((inductance.0 - 0.01).abs() < 1e-15);
+        assert_eq!(format!("{:#}", inductance), "10 millihenries");
+    }
+}
@@ -0,0 +1,39 @@
+use crate::{define_measurement, define_units};
+
+define_measurement! {
+    /// A measurement of irradiance, the power received per area, with a base unit of
+    /// watts per square meter.
+    Irradiance = [mass: 1, time: -3]
+}
+
+define_units! { Irradiance =>
+    watts_per_square_meter: ("W/m²", 1.0, Si, "watt per square meter", "watts per square meter"),
+    solar_constant: ("S☉", 1361.0, Astronomical, "solar constant", "solar constants"),
+    // The zero point of the IAU 2015 bolometric magnitude scale.
+    #[logarithmic(-2.5)]
+    apparent_magnitude: ("mag", 2.518021002e-8, Astronomical, "magnitude", "magnitudes"),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn magnitudes() {
+        let zero = Irradiance::new::<apparent_magnitude>(0.0);
+        assert_eq!(zero, Irradiance(2.518021002e-8));
+
+        // Five magnitudes are a factor of one hundred, and brighter is smaller.
+        let faint = Irradiance::new::<apparent_magnitude>(5.0);
+        assert!((zero / faint - 100.0).abs() < 1e-9);
+        assert!(faint < zero);
+
+        let sun = Irradiance::new::<solar_constant>(1.0);
+        assert!((sun.get::<apparent_magnitude>() + 26.83).abs() < 0.01);
+        assert_eq!(sun.to_string(), "1.00 S☉");
+        assert_eq!(
+            Irradiance::parse("5 mag").map(|value| value.get::<apparent_magnitude>().round()),
+            Ok(5.0)
+        );
+    }
+}
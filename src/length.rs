@@ -1,26 +1,17 @@
-use std::ops::Mul;
-
-use crate::{define_measurement, define_units, time::Time, velocity::Velocity};
+use crate::{define_measurement, define_units};
 
 define_measurement! {
     /// A measurement of distance, with a base unit of meters.
-    Length
+    Length = [length: 1]
 }
 
 define_units! { Length =>
-    astronomical_unit: ("AU", 1.496e11),
-    solar_radius: ("R☉", 6.957e8),
-    earth_radius: ("R⊕", 6.371e6),
-    kilometer: ("km", 1000.0),
-    meter: ("m", 1.0),
-    centimeter: ("cm", 0.01),
-}
-
-impl Mul<Time> for Velocity {
-    type Output = Length;
-    fn mul(self, rhs: Time) -> Self::Output {
-        Length(self.0 * rhs.0)
-    }
+    astronomical_unit: ("AU", 1.496e11, Astronomical, "astronomical unit", "astronomical units"),
+    solar_radius: ("R☉", 6.957e8, Astronomical, "solar radius", "solar radii"),
+    earth_radius: ("R⊕", 6.371e6, Astronomical, "Earth radius", "Earth radii"),
+    kilometer: ("km", 1000.0, Si, "kilometer", "kilometers"),
+    meter: ("m", 1.0, Si, "meter", "meters"),
+    centimeter: ("cm", 0.01, Si, "centimeter", "centimeters"),
 }
 
 #[cfg(test)]
@@ -1,55 +1,232 @@
-#[macro_use]
 mod macros;
 pub mod acceleration;
 pub mod angle;
+pub mod area;
+pub mod capacitance;
+pub mod charge;
+pub mod current;
 pub mod density;
+pub mod display;
 pub mod energy;
+pub mod expression;
 pub mod flow;
 pub mod force;
+pub mod format;
+pub mod frequency;
+pub mod fuel;
+pub mod inductance;
+pub mod irradiance;
 pub mod length;
+pub mod magnetic;
 pub mod mass;
+pub mod mixed;
+pub mod parse;
 #[cfg(feature = "position")]
 pub mod position;
 pub mod power;
+pub mod prefix;
 pub mod pressure;
+pub mod quantity;
+pub mod registry;
+pub mod relations;
+pub mod resistance;
+pub mod rotation;
+pub mod serde;
+pub mod substance;
 pub mod temperature;
 pub mod time;
 pub mod torque;
 pub mod velocity;
+pub mod viscosity;
+pub mod voltage;
 pub mod volume;
 
-pub(crate) use paste;
+#[doc(hidden)]
+pub use paste;
+
+pub use parse::ParseMeasurementError;
+pub use registry::{UnitHandle, UnitMismatchError};
 
 pub mod prelude {
     pub use crate::acceleration::*;
     pub use crate::angle::*;
+    pub use crate::area::*;
+    pub use crate::capacitance::*;
+    pub use crate::charge::*;
+    pub use crate::current::*;
     pub use crate::density::*;
     pub use crate::energy::*;
     pub use crate::flow::*;
     pub use crate::force::*;
+    pub use crate::frequency::*;
+    pub use crate::fuel::*;
+    pub use crate::inductance::*;
+    pub use crate::irradiance::*;
     pub use crate::length::*;
+    pub use crate::magnetic::*;
     pub use crate::mass::*;
     #[cfg(feature = "position")]
     pub use crate::position::*;
     pub use crate::power::*;
     pub use crate::pressure::*;
+    pub use crate::resistance::*;
+    pub use crate::rotation::*;
+    pub use crate::substance::*;
     pub use crate::temperature::*;
     pub use crate::time::*;
     pub use crate::torque::*;
     pub use crate::velocity::*;
+    pub use crate::viscosity::*;
+    pub use crate::voltage::*;
     pub use crate::volume::*;
 
-    pub use crate::{BesiUnit};
+    pub use crate::display::DisplayPolicy;
+    pub use crate::format::{FormatOptions, Notation};
+    pub use crate::mixed::{MixedFormat, Rounding};
+    pub use crate::prefix::SiPrefix;
+    pub use crate::quantity::{DynQuantity, QuantityKind};
+    pub use crate::{
+        BesiUnit, Conversion, Measurement, ParseMeasurementError, UnitHandle, UnitSystem,
+    };
     pub use num_traits::Zero;
 }
 
 
+/// A measurement type, as generated by `define_measurement!`.
+pub trait Measurement: Copy + 'static {
+    /// The type name of the measurement (e.g., `"Length"`).
+    const NAME: &'static str;
+    /// The dimension of the measurement, e.g. `length: 1, time: -1` for `Velocity`.
+    const DIMENSION: quantity::Dimension;
+    /// Whether the measurement is an amount or a point on an affine scale, such as `Temperature`.
+    const KIND: quantity::QuantityKind = quantity::QuantityKind::Amount;
+
+    /// Creates the measurement from a value in its base unit.
+    fn from_base(value: f64) -> Self;
+
+    /// Gets the value in its base unit.
+    fn to_base(&self) -> f64;
+}
+
+/// Plain numbers, such as the ratio of two lengths.
+impl Measurement for f64 {
+    const NAME: &'static str = "f64";
+    const DIMENSION: quantity::Dimension = quantity::Dimension::NONE;
+
+    fn from_base(value: f64) -> Self {
+        value
+    }
+
+    fn to_base(&self) -> f64 {
+        *self
+    }
+}
+
 pub trait BesiUnit: 'static {
+    /// The measurement this unit belongs to.
+    type Measurement: Measurement;
+    /// The symbol for the unit, e.g., "km".
+    const SYMBOL: &'static str;
     /// The scale factor to convert this unit to the base unit (meters for Length).
     const SCALE_FACTOR: f64;
+    /// The base-unit value of zero in this unit, e.g. `273.15` for degrees Celsius.
+    const OFFSET: f64 = 0.0;
+    /// How values in this unit are converted to the base unit.
+    const CONVERSION: Conversion = Conversion::Linear;
+
+    /// Converts `value` in this unit to the base unit.
+    fn to_base(value: f64) -> f64 {
+        Self::CONVERSION.to_base(value, Self::SCALE_FACTOR, Self::OFFSET)
+    }
+
+    /// Converts `value` in the base unit to this unit.
+    fn from_base(value: f64) -> f64 {
+        Self::CONVERSION.from_base(value, Self::SCALE_FACTOR, Self::OFFSET)
+    }
+}
+
+/// How a unit converts values to its base unit, given its scale factor and offset.
+#[derive(Clone, Copy, Debug)]
+pub enum Conversion {
+    /// `value * scale_factor + offset`, which is the case for most units.
+    Linear,
+    /// `scale_factor / value`, e.g. for fuel economies in kilometers per liter.
+    Reciprocal,
+    /// `scale_factor * 10^(value / step)`, e.g. a step of `10.0` for decibels and of
+    /// `-2.5` for stellar magnitudes.
+    Logarithmic(f64),
+    /// Arbitrary functions converting to and from the base unit.
+    Functions {
+        to_base: fn(f64) -> f64,
+        from_base: fn(f64) -> f64,
+    },
+}
+
+impl Conversion {
+    /// Converts `value` to the base unit.
+    pub fn to_base(&self, value: f64, scale_factor: f64, offset: f64) -> f64 {
+        match self {
+            Self::Linear => value * scale_factor + offset,
+            Self::Reciprocal => scale_factor / value,
+            Self::Logarithmic(step) => scale_factor * 10f64.powf(value / step),
+            Self::Functions { to_base, .. } => to_base(value),
+        }
+    }
+
+    /// Converts `value` from the base unit.
+    pub fn from_base(&self, value: f64, scale_factor: f64, offset: f64) -> f64 {
+        match self {
+            Self::Linear => (value - offset) / scale_factor,
+            Self::Reciprocal => scale_factor / value,
+            Self::Logarithmic(step) => step * (value / scale_factor).log10(),
+            Self::Functions { from_base, .. } => from_base(value),
+        }
+    }
+
+    /// Whether the conversion is [`Conversion::Linear`].
+    pub fn is_linear(&self) -> bool {
+        matches!(self, Self::Linear)
+    }
+}
+
+/// Functions are compared by address, which may differ between codegen units.
+impl PartialEq for Conversion {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Linear, Self::Linear) | (Self::Reciprocal, Self::Reciprocal) => true,
+            (Self::Logarithmic(a), Self::Logarithmic(b)) => a == b,
+            (
+                Self::Functions { to_base, from_base },
+                Self::Functions {
+                    to_base: other_to_base,
+                    from_base: other_from_base,
+                },
+            ) => {
+                std::ptr::fn_addr_eq(*to_base, *other_to_base)
+                    && std::ptr::fn_addr_eq(*from_base, *other_from_base)
+            }
+            _ => false,
+        }
+    }
+}
+
+/// The system of units a unit belongs to, used to filter display units.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum UnitSystem {
+    /// SI units and their prefixed forms (m, km, s, ms, W, ...).
+    Si,
+    /// Non-SI units accepted for use with SI (minute, hour, liter, tonne, degree, ...).
+    Metric,
+    /// Astronomical units (AU, solar radius, solar mass, sidereal day, ...).
+    Astronomical,
+    /// Imperial and US customary units.
+    Imperial,
+    /// Any other unit (calendar months, standard gravity, revolutions, ...).
+    Other,
 }
 
 /// Holds information for choosing and displaying a unit.
+#[derive(Debug, PartialEq)]
 pub struct UnitDisplayInfo {
     /// The type name of the measurement this unit belongs to (e.g., `"Length"`).
     pub measurement_type_name: &'static str,
@@ -57,6 +234,131 @@ pub struct UnitDisplayInfo {
     pub symbol: &'static str,
     /// The scale factor relative to the base unit.
     pub scale_factor: f64,
+    /// The system of units this unit belongs to.
+    pub system: UnitSystem,
+    /// The singular long name of the unit, e.g., "kilometer".
+    pub singular: &'static str,
+    /// The plural long name of the unit, e.g., "kilometers".
+    pub plural: &'static str,
+    /// The SI prefix of units generated by `#[si_prefixes]` or marked with `#[prefix(...)]`.
+    pub prefix: Option<prefix::SiPrefix>,
+    /// The base-unit value of zero in this unit, e.g. `273.15` for degrees Celsius.
+    pub offset: f64,
+    /// Whether the unit is only displayed when a [`display::DisplayPolicy`] fixes it.
+    pub explicit: bool,
+    /// How values are converted to the base unit.
+    pub conversion: Conversion,
+    /// The dimension of the measurement this unit belongs to.
+    pub dimension: quantity::Dimension,
+    /// The kind of the measurement this unit belongs to.
+    pub kind: quantity::QuantityKind,
+}
+
+impl UnitDisplayInfo {
+    /// Converts `value` in this unit to the base unit.
+    pub fn to_base(&self, value: f64) -> f64 {
+        self.conversion.to_base(value, self.scale_factor, self.offset)
+    }
+
+    /// Converts `value` in this unit to the base unit, unless it is outside the unit's range.
+    ///
+    /// Reciprocal units only take positive values (`0 km/L` is no fuel economy at all),
+    /// and other non-linear conversions must give a finite value.
+    pub fn try_to_base(&self, value: f64) -> Option<f64> {
+        let base = self.to_base(value);
+        match self.conversion {
+            Conversion::Linear => Some(base),
+            Conversion::Reciprocal if value <= 0.0 => None,
+            _ => base.is_finite().then_some(base),
+        }
+    }
+
+    /// Converts `value` in the base unit to this unit.
+    pub fn from_base(&self, value: f64) -> f64 {
+        self.conversion.from_base(value, self.scale_factor, self.offset)
+    }
+
+    /// Whether this is the base unit of its measurement, in which values are stored.
+    pub fn is_base(&self) -> bool {
+        self.scale_factor == 1.0 && self.offset == 0.0 && self.conversion.is_linear()
+    }
+
+    /// Iterates over the registered units of the given measurement.
+    pub fn units_of(measurement: &str) -> impl Iterator<Item = &'static UnitDisplayInfo> {
+        inventory::iter::<UnitDisplayInfo>
+            .into_iter()
+            .filter(move |unit| unit.measurement_type_name == measurement)
+    }
+
+    /// Gets the registered information for the unit `U`.
+    pub fn of<U: BesiUnit>() -> &'static UnitDisplayInfo {
+        Self::units_of(U::Measurement::NAME)
+            .find(|unit| unit.symbol == U::SYMBOL)
+            .unwrap_or_else(|| panic!("{} is not a registered {} unit", U::SYMBOL, U::Measurement::NAME))
+    }
+
+    /// Selects the unit used to display `value` (in base units), considering every
+    /// registered unit of the measurement but those with centi, deci, deca or hecto prefixes.
+    /// See [`display::DisplayPolicy`] to restrict the choice.
+    pub fn best_for(measurement: &str, value: f64) -> &'static UnitDisplayInfo {
+        Self::best_of(measurement, value, |unit| {
+            unit.prefix.is_none_or(|prefix| prefix.is_engineering())
+        })
+            .unwrap_or_else(|| panic!("No besi units defined for {measurement}"))
+    }
+
+    /// Selects the largest unit accepted by `filter` that keeps the displayed value at or
+    /// above one, or the smallest such unit otherwise. Zero is shown in the base unit if it is
+    /// accepted. Ties are broken by [`UnitSystem`] order. Explicit units and units without a
+    /// linear [`Conversion`] are never selected.
+    ///
+    /// Measurements with offset units, such as temperatures, are shown in the offset unit of
+    /// the first accepted [`UnitSystem`] while its value stays within ±100 and isn't a small
+    /// fraction, and in the system's unit without an offset otherwise.
+    pub(crate) fn best_of(
+        measurement: &str,
+        value: f64,
+        filter: impl Fn(&UnitDisplayInfo) -> bool,
+    ) -> Option<&'static UnitDisplayInfo> {
+        let units = || {
+            Self::units_of(measurement)
+                .filter(|unit| !unit.explicit && unit.conversion.is_linear() && filter(unit))
+        };
+        if units().any(|unit| unit.offset != 0.0) {
+            return Self::best_affine(units, value);
+        }
+        if value == 0.0
+            && let Some(base) = units().find(|unit| unit.is_base())
+        {
+            return Some(base);
+        }
+        units()
+            .filter(|unit| value.abs() >= unit.scale_factor)
+            .max_by(|a, b| {
+                (a.scale_factor.total_cmp(&b.scale_factor)).then(b.system.cmp(&a.system))
+            })
+            .or_else(|| {
+                units().min_by(|a, b| {
+                    (a.scale_factor.total_cmp(&b.scale_factor)).then(a.system.cmp(&b.system))
+                })
+            })
+    }
+
+    fn best_affine<I: Iterator<Item = &'static UnitDisplayInfo>>(
+        units: impl Fn() -> I,
+        value: f64,
+    ) -> Option<&'static UnitDisplayInfo> {
+        let system = units().map(|unit| unit.system).min()?;
+        let in_system = || units().filter(move |unit| unit.system == system);
+        in_system()
+            .filter(|unit| unit.offset != 0.0)
+            .find(|unit| {
+                let shown = unit.from_base(value).abs();
+                shown <= 100.0 && (shown >= 1.0 || shown <= 1e-9)
+            })
+            .or_else(|| in_system().find(|unit| unit.offset == 0.0))
+            .or_else(|| in_system().next())
+    }
 }
 
 // Create a global, distributed collection of `UnitDisplayInfo` structs.
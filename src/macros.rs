@@ -1,26 +1,124 @@
+/// Defines the units of a measurement and registers them for display and parsing.
+///
+/// Each unit is given as `name: (symbol, scale_factor, system, singular, plural)`, where
+/// `system` is a [`UnitSystem`](crate::UnitSystem) variant and the long names are used by
+/// `{:#}` formatting and [`registry::find_by_name`](crate::registry::find_by_name).
+///
+/// Units can be marked with attributes:
+///
+/// * `#[si_prefixes]` also generates a unit for every [`SiPrefix`](crate::prefix::SiPrefix),
+///   named, symbolized and scaled accordingly (`kilowatt`, `"kW"`, `1e3 * factor`, ...).
+/// * `#[offset(value)]` adds `value` to conversions to the base unit, for units such as
+///   degrees Celsius (`#[offset(273.15)]`) whose zero isn't the base unit's zero.
+/// * `#[prefix(KILO)]` records that a unit already carries an [`SiPrefix`](crate::prefix::SiPrefix),
+///   as kilograms do, so engineering notation prefixes the unprefixed unit (grams) instead.
+/// * `#[explicit]` keeps `Display` from ever selecting the unit on its own. It can still
+///   be fixed with a [`DisplayPolicy`](crate::display::DisplayPolicy).
+/// * `#[reciprocal]`, `#[logarithmic(step)]` and `#[conversion(to_base, from_base)]` replace
+///   the linear conversion with a [`Conversion`](crate::Conversion), for units such as
+///   kilometers per liter or decibels. Like explicit units, they are only displayed when fixed.
 #[macro_export]
 macro_rules! define_units {
-    ($measurement:ident => $($unit:ident: ($symbol:expr, $factor:expr)),* $(,)?) => {
+    // Collects the attributes of a unit into `[prefixes] [prefix] [explicit] [offset] [conversion]`.
+    (@attributes $unit:tt [$prefixes:ident] [$($prefix:tt)*] [$explicit:expr] [$offset:expr] [$($conversion:tt)*] si_prefixes $($rest:tt)*) => {
+        $crate::define_units!(@attributes $unit [si_prefixes] [$($prefix)*] [$explicit] [$offset] [$($conversion)*] $($rest)*);
+    };
+    (@attributes $unit:tt [$prefixes:ident] [$($prefix:tt)*] [$explicit:expr] [$offset:expr] [$($conversion:tt)*] prefix($constant:ident) $($rest:tt)*) => {
+        $crate::define_units!(@attributes $unit [$prefixes] [Some($crate::prefix::SiPrefix::$constant)] [$explicit] [$offset]
+            [$($conversion)*] $($rest)*);
+    };
+    (@attributes $unit:tt [$prefixes:ident] [$($prefix:tt)*] [$explicit:expr] [$offset:expr] [$($conversion:tt)*] explicit $($rest:tt)*) => {
+        $crate::define_units!(@attributes $unit [$prefixes] [$($prefix)*] [true] [$offset] [$($conversion)*] $($rest)*);
+    };
+    (@attributes $unit:tt [$prefixes:ident] [$($prefix:tt)*] [$explicit:expr] [$offset:expr] [$($conversion:tt)*] offset($value:expr) $($rest:tt)*) => {
+        $crate::define_units!(@attributes $unit [$prefixes] [$($prefix)*] [$explicit] [$value] [$($conversion)*] $($rest)*);
+    };
+    (@attributes $unit:tt [$prefixes:ident] [$($prefix:tt)*] [$explicit:expr] [$offset:expr] [$($conversion:tt)*] reciprocal $($rest:tt)*) => {
+        $crate::define_units!(@attributes $unit [$prefixes] [$($prefix)*] [$explicit] [$offset] [Reciprocal] $($rest)*);
+    };
+    (@attributes $unit:tt [$prefixes:ident] [$($prefix:tt)*] [$explicit:expr] [$offset:expr] [$($conversion:tt)*] logarithmic($step:expr) $($rest:tt)*) => {
+        $crate::define_units!(@attributes $unit [$prefixes] [$($prefix)*] [$explicit] [$offset] [Logarithmic($step)] $($rest)*);
+    };
+    (@attributes $unit:tt [$prefixes:ident] [$($prefix:tt)*] [$explicit:expr] [$offset:expr] [$($conversion:tt)*] conversion($to_base:expr, $from_base:expr) $($rest:tt)*) => {
+        $crate::define_units!(@attributes $unit [$prefixes] [$($prefix)*] [$explicit] [$offset]
+            [Functions { to_base: $to_base, from_base: $from_base }] $($rest)*);
+    };
+    (@attributes (
+        $measurement:ident, $unit:ident, $symbol:expr, $factor:expr, $system:expr, $singular:expr, $plural:expr
+    ) [no_prefixes] [$($prefix:tt)*] [$explicit:expr] [$offset:expr] [$($conversion:tt)*]) => {
+        $crate::define_units!(@one $measurement, $unit, $symbol, $factor, $system, $singular, $plural, $($prefix)*, $explicit, $offset,
+            $crate::Conversion::$($conversion)*);
+    };
+    (@attributes (
+        $measurement:ident, $unit:ident, $symbol:expr, $factor:expr, $system:expr, $singular:expr, $plural:expr
+    ) [si_prefixes] [None] [$explicit:expr] [$offset:expr] [Linear]) => {
+        $crate::define_units!(@one $measurement, $unit, $symbol, $factor, $system, $singular, $plural, None, $explicit, $offset,
+            $crate::Conversion::Linear);
+        $crate::define_units!(@prefixes ($measurement, $unit, $symbol, $factor, $system, $singular, $plural, $explicit),
+            quecto QUECTO "q" 1e-30, ronto RONTO "r" 1e-27, yocto YOCTO "y" 1e-24, zepto ZEPTO "z" 1e-21,
+            atto ATTO "a" 1e-18, femto FEMTO "f" 1e-15, pico PICO "p" 1e-12, nano NANO "n" 1e-9,
+            micro MICRO "µ" 1e-6, milli MILLI "m" 1e-3, centi CENTI "c" 1e-2, deci DECI "d" 1e-1,
+            deca DECA "da" 1e1, hecto HECTO "h" 1e2, kilo KILO "k" 1e3, mega MEGA "M" 1e6,
+            giga GIGA "G" 1e9, tera TERA "T" 1e12, peta PETA "P" 1e15, exa EXA "E" 1e18,
+            zetta ZETTA "Z" 1e21, yotta YOTTA "Y" 1e24, ronna RONNA "R" 1e27, quetta QUETTA "Q" 1e30
+        );
+    };
+    (@prefixes (
+        $measurement:ident, $unit:ident, $symbol:expr, $factor:expr, $system:expr, $singular:expr, $plural:expr, $explicit:expr
+    ), $($name:ident $constant:ident $prefix:literal $scale:literal),*) => {
         $crate::paste::paste! {
-            pub trait [<$measurement Unit>]: $crate::BesiUnit {}
+            $(
+                $crate::define_units!(@one $measurement, [<$name $unit>], concat!($prefix, $symbol), $scale * $factor,
+                    $system, concat!(stringify!($name), $singular), concat!(stringify!($name), $plural),
+                    Some($crate::prefix::SiPrefix::$constant), $explicit, 0.0, $crate::Conversion::Linear);
+            )*
         }
-        $(
-            #[allow(non_camel_case_types)]
-            #[derive(Default, Debug, Clone, Copy)]
-            pub struct $unit;
+    };
+    (@one $measurement:ident, $unit:ident, $symbol:expr, $factor:expr, $system:expr, $singular:expr, $plural:expr,
+        $prefix:expr, $explicit:expr, $offset:expr, $conversion:expr) => {
+        #[allow(non_camel_case_types)]
+        #[derive(Default, Debug, Clone, Copy)]
+        pub struct $unit;
 
-            $crate::paste::paste! {
-                impl $crate::BesiUnit for $unit { const SCALE_FACTOR: f64 = $factor; }
-                impl [<$measurement Unit>] for $unit {}
+        $crate::paste::paste! {
+            impl $crate::BesiUnit for $unit {
+                type Measurement = $measurement;
+                const SYMBOL: &'static str = $symbol;
+                const SCALE_FACTOR: f64 = $factor;
+                const OFFSET: f64 = $offset;
+                const CONVERSION: $crate::Conversion = $conversion;
             }
+            impl [<$measurement Unit>] for $unit {}
+        }
 
-            inventory::submit! {
-                $crate::UnitDisplayInfo {
-                    measurement_type_name: stringify!($measurement),
-                    symbol: $symbol,
-                    scale_factor: $factor,
-                }
+        inventory::submit! {
+            $crate::UnitDisplayInfo {
+                measurement_type_name: stringify!($measurement),
+                symbol: $symbol,
+                scale_factor: $factor,
+                system: $system,
+                singular: $singular,
+                plural: $plural,
+                prefix: $prefix,
+                offset: $offset,
+                explicit: $explicit,
+                conversion: $conversion,
+                dimension: <$measurement as $crate::Measurement>::DIMENSION,
+                kind: <$measurement as $crate::Measurement>::KIND,
             }
+        }
+    };
+    ($measurement:ident => $($(#[$attribute:ident $(($($argument:tt)*))?])* $unit:ident: (
+        $symbol:expr, $factor:expr, $system:ident, $singular:expr, $plural:expr
+    )),* $(,)?) => {
+        $crate::paste::paste! {
+            pub trait [<$measurement Unit>]: $crate::BesiUnit<Measurement = $measurement> {}
+        }
+        $(
+            $crate::define_units!(@attributes (
+                $measurement, $unit, $symbol, $factor,
+                $crate::UnitSystem::$system, $singular, $plural
+            ) [no_prefixes] [None] [false] [0.0] [Linear] $($attribute $(($($argument)*))?)*);
         )*
     };
 }
@@ -30,44 +128,27 @@ macro_rules! define_units {
 /// This macro creates the struct and implements `new`, `get`, `ZERO`,
 /// standard math operators, and a `Display` trait that automatically
 /// selects the best unit.
+///
+/// The name is followed by the exponents of its [`Dimension`](crate::quantity::Dimension),
+/// e.g. `Velocity = [length: 1, time: -1]`. Omitted exponents are zero.
+///
+/// Measurements of a point on an affine scale, such as absolute temperatures, name the
+/// measurement of their differences instead: `Temperature = [temperature: 1], differences:
+/// TemperatureDifference`. They can't be added or scaled; subtracting two of them gives a
+/// difference, which can be added to or subtracted from them.
+///
+/// Measurements that share the dimension of another one without being interchangeable
+/// with it name that measurement: `FuelConsumption = [length: 2], distinct_from: Area`.
+/// A [`DynQuantity`](crate::quantity::DynQuantity) of one never converts to the other.
 #[macro_export]
 macro_rules! define_measurement {
-    (
-        $(#[$outer:meta])* // The measurement name (e.g., `Length`).
-        $name:ident
-    ) => {
-        $crate::paste::paste! {
-
-        $(#[$outer])*
-        #[cfg_attr(feature = "reflect", derive(bevy_reflect::Reflect))]
-        #[derive(Clone, Copy, Default, Debug, PartialEq, PartialOrd)]
-        pub struct $name(pub f64);
-
-        impl $name {
-            /// A constant for the zero value.
-            pub const ZERO: Self = Self(0.0);
-
-            /// Creates a new value from a given unit.
-            pub fn new<T: [<$name Unit>]>(value: f64) -> Self {
-                Self(value * T::SCALE_FACTOR)
-            }
-
-            /// Gets the value in terms of a specific unit.
-            pub fn get<T: [<$name Unit>]>(&self) -> f64 {
-                self.0 / T::SCALE_FACTOR
-            }
-        }
-
-        impl serde::Serialize for $name
-        {
-            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-            where
-                S: serde::Serializer
-            {
-                self.0.serialize(serializer)
-            }
-        }
-
+    (@kind $name:ident) => { $crate::quantity::QuantityKind::Amount };
+    (@kind $name:ident differences $difference:ident) => { $crate::quantity::QuantityKind::Absolute };
+    (@kind $name:ident distinct_from $other:ident) => {
+        $crate::quantity::QuantityKind::Distinct(stringify!($name))
+    };
+    // Linear measurements add, scale and divide like plain numbers.
+    (@arithmetic $name:ident) => {
         impl num_traits::Zero for $name
         {
             fn zero() -> Self {
@@ -79,38 +160,6 @@ macro_rules! define_measurement {
             }
         }
 
-        impl<'de> serde::Deserialize<'de> for $name
-        {
-            fn deserialize<De>(deserializer: De) -> Result<Self, De::Error>
-            where
-                De: serde::Deserializer<'de>,
-            {
-                let value: f64 = serde::Deserialize::deserialize(deserializer)?;
-                Ok($name(value))
-            }
-        }
-
-        impl std::fmt::Display for $name {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                let value = self.0;
-
-                let best_unit = inventory::iter::<$crate::UnitDisplayInfo>
-                    .into_iter()
-                    .filter(|unit| unit.measurement_type_name == stringify!($name))
-                    .filter(|unit| value.abs() >= unit.scale_factor)
-                    .max_by(|a, b| a.scale_factor.partial_cmp(&b.scale_factor).unwrap())
-                    .unwrap_or_else(|| {
-                        inventory::iter::<$crate::UnitDisplayInfo>
-                            .into_iter()
-                            .filter(|unit| unit.measurement_type_name == stringify!($name))
-                            .min_by(|a, b| a.scale_factor.partial_cmp(&b.scale_factor).unwrap())
-                            .unwrap_or_else(|| panic!("No busi units defined for {}", stringify!($name)))
-                    });
-
-                write!(f, "{:.2} {}", value / best_unit.scale_factor, best_unit.symbol)
-            }
-        }
-
         impl std::ops::Add for $name {
             type Output = Self;
             fn add(self, rhs: Self) -> Self::Output { Self(self.0 + rhs.0) }
@@ -160,9 +209,172 @@ macro_rules! define_measurement {
                 self.0 /= rhs;
             }
         }
+    };
+    // Measurements sharing the dimension of another one otherwise behave like it.
+    (@arithmetic $name:ident distinct_from $other:ident) => {
+        $crate::define_measurement!(@arithmetic $name);
+
+        const _: () = assert!(
+            <$name as $crate::Measurement>::DIMENSION.const_eq(<$other as $crate::Measurement>::DIMENSION),
+            concat!("the dimensions of ", stringify!($name), " and ", stringify!($other), " differ"),
+        );
+    };
+    // Affine measurements, such as absolute temperatures, only add and subtract differences.
+    (@arithmetic $name:ident differences $difference:ident) => {
+        impl std::ops::Sub for $name {
+            type Output = $difference;
+            fn sub(self, rhs: Self) -> Self::Output { $difference(self.0 - rhs.0) }
+        }
+
+        impl std::ops::Add<$difference> for $name {
+            type Output = Self;
+            fn add(self, rhs: $difference) -> Self::Output { Self(self.0 + rhs.0) }
+        }
+
+        impl std::ops::AddAssign<$difference> for $name {
+            fn add_assign(&mut self, rhs: $difference) {
+                self.0 += rhs.0;
+            }
+        }
+
+        impl std::ops::Sub<$difference> for $name {
+            type Output = Self;
+            fn sub(self, rhs: $difference) -> Self::Output { Self(self.0 - rhs.0) }
+        }
+
+        impl std::ops::SubAssign<$difference> for $name {
+            fn sub_assign(&mut self, rhs: $difference) {
+                self.0 -= rhs.0;
+            }
+        }
+    };
+    (
+        $(#[$outer:meta])* // The measurement name (e.g., `Length`).
+        $name:ident = [$($axis:ident: $exponent:expr),* $(,)?]
+        $(, $clause:ident: $related:ident)?
+    ) => {
+        $crate::paste::paste! {
+
+        $(#[$outer])*
+        #[cfg_attr(feature = "reflect", derive(bevy_reflect::Reflect))]
+        #[derive(Clone, Copy, Default, Debug, PartialEq, PartialOrd)]
+        pub struct $name(pub f64);
+
+        impl $name {
+            /// A constant for the zero value.
+            pub const ZERO: Self = Self(0.0);
+
+            /// Creates a new value from a given unit.
+            pub fn new<T: [<$name Unit>]>(value: f64) -> Self {
+                Self(T::to_base(value))
+            }
+
+            /// Gets the value in terms of a specific unit.
+            pub fn get<T: [<$name Unit>]>(&self) -> f64 {
+                T::from_base(self.0)
+            }
+
+            /// Creates a new value from a unit selected at runtime.
+            pub fn new_dyn(value: f64, unit: &$crate::registry::UnitHandle) -> Result<Self, $crate::registry::UnitMismatchError> {
+                unit.expect(stringify!($name)).map(|unit| Self(unit.to_base(value)))
+            }
+
+            /// Gets the value in terms of a unit selected at runtime.
+            pub fn get_dyn(&self, unit: &$crate::registry::UnitHandle) -> Result<f64, $crate::registry::UnitMismatchError> {
+                unit.expect(stringify!($name)).map(|unit| unit.from_base(self.0))
+            }
+
+            /// Displays the value according to `policy` instead of the global display policy.
+            pub fn display_with<'a>(&self, policy: &'a $crate::display::DisplayPolicy) -> $crate::display::MeasurementDisplay<'a, Self> {
+                $crate::display::MeasurementDisplay::new(*self, policy)
+            }
+
+            /// Displays the value according to `options`, e.g. in scientific notation.
+            pub fn display_as<'a>(&self, options: &'a $crate::format::FormatOptions) -> $crate::format::OptionsDisplay<'a, Self> {
+                $crate::format::OptionsDisplay::new(*self, options)
+            }
+
+            /// Displays the value broken down across several units, such as `"1 h 23 min 45 s"`.
+            pub fn display_mixed<'a>(&self, format: &'a $crate::mixed::MixedFormat<Self>) -> $crate::mixed::MixedDisplay<'a, Self> {
+                $crate::mixed::MixedDisplay::new(*self, format)
+            }
+
+            /// Parses a value such as `"1.5 km"`, resolving the unit against the registered units.
+            pub fn parse(s: &str) -> Result<Self, $crate::ParseMeasurementError> {
+                $crate::parse::parse_measurement(stringify!($name), s).map(Self)
+            }
+        }
+
+        impl std::str::FromStr for $name {
+            type Err = $crate::ParseMeasurementError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Self::parse(s)
+            }
+        }
+
+        impl $crate::Measurement for $name {
+            const NAME: &'static str = stringify!($name);
+            const DIMENSION: $crate::quantity::Dimension = $crate::quantity::Dimension {
+                $($axis: $exponent,)*
+                ..$crate::quantity::Dimension::NONE
+            };
+            const KIND: $crate::quantity::QuantityKind = $crate::define_measurement!(@kind $name $($clause $related)?);
+
+            fn from_base(value: f64) -> Self {
+                Self(value)
+            }
+
+            fn to_base(&self) -> f64 {
+                self.0
+            }
+        }
+
+        impl From<$name> for $crate::quantity::DynQuantity {
+            fn from(value: $name) -> Self {
+                Self::of(value)
+            }
+        }
+
+        impl TryFrom<$crate::quantity::DynQuantity> for $name {
+            type Error = $crate::quantity::DimensionMismatchError;
+
+            fn try_from(quantity: $crate::quantity::DynQuantity) -> Result<Self, Self::Error> {
+                quantity.to()
+            }
+        }
+
+        impl serde::Serialize for $name
+        {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer
+            {
+                $crate::serde::serialize_measurement(stringify!($name), self.0, serializer)
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $name
+        {
+            fn deserialize<De>(deserializer: De) -> Result<Self, De::Error>
+            where
+                De: serde::Deserializer<'de>,
+            {
+                $crate::serde::deserialize_measurement(stringify!($name), deserializer).map($name)
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                let value = self.0;
+                let best_unit = $crate::display::DisplayPolicy::select_global(stringify!($name), value);
+                $crate::format::write_unit(f, best_unit.from_base(value), best_unit)
+            }
+        }
 
         impl std::cmp::Eq for $name {}
 
+        #[allow(clippy::derive_ord_xor_partial_ord)]
         impl std::cmp::Ord for $name {
             fn cmp(&self, other: &Self) -> std::cmp::Ordering {
                 self.0.partial_cmp(&other.0).unwrap()
@@ -173,5 +385,102 @@ macro_rules! define_measurement {
         impl AsMut<f64> for $name { fn as_mut(&mut self) -> &mut f64 { &mut self.0 } }
 
         }
+
+        $crate::define_measurement!(@arithmetic $name $($clause $related)?);
+    };
+}
+
+/// Defines the operators relating measurements, with every commuted and inverse form.
+///
+/// `Velocity * Time = Length` implements `Velocity * Time` and `Time * Velocity` as
+/// `Length`, and `Length / Time` and `Length / Velocity`. `A / B = C` is the same as
+/// `C * B = A`, and `Length ^ 2 = Area` implements `Length * Length` and `Area / Length`.
+/// Plain numbers are written `f64`, as in `Frequency * Time = f64`, which also implements
+/// `1.0 / time`. The dimensions of the measurements are checked at compile time.
+///
+/// ```ignore
+/// define_relation! {
+///     Velocity * Time = Length;
+///     Acceleration * Time = Velocity;
+/// }
+/// ```
+#[macro_export]
+macro_rules! define_relation {
+    (@mul $lhs:ident, $rhs:ident, $output:ident) => {
+        impl std::ops::Mul<$rhs> for $lhs {
+            type Output = $output;
+            fn mul(self, rhs: $rhs) -> Self::Output {
+                use $crate::Measurement;
+                $output::from_base(self.to_base() * rhs.to_base())
+            }
+        }
+    };
+    (@div $lhs:ident, $rhs:ident, $output:ident) => {
+        impl std::ops::Div<$rhs> for $lhs {
+            type Output = $output;
+            fn div(self, rhs: $rhs) -> Self::Output {
+                use $crate::Measurement;
+                $output::from_base(self.to_base() / rhs.to_base())
+            }
+        }
     };
+    (@check $lhs:ident, $rhs:ident, $output:ident) => {
+        const _: () = assert!(
+            <$lhs as $crate::Measurement>::DIMENSION
+                .mul(<$rhs as $crate::Measurement>::DIMENSION)
+                .const_eq(<$output as $crate::Measurement>::DIMENSION),
+            concat!("the dimensions of ", stringify!($lhs), " * ", stringify!($rhs), " and ", stringify!($output), " differ"),
+        );
+    };
+    (@relation $a:ident * $b:ident = $c:ident) => {
+        $crate::define_relation!(@check $a, $b, $c);
+        $crate::define_relation!(@mul $a, $b, $c);
+        $crate::define_relation!(@mul $b, $a, $c);
+        $crate::define_relation!(@div $c, $b, $a);
+        $crate::define_relation!(@div $c, $a, $b);
+    };
+    (@relation $a:ident / $b:ident = $c:ident) => {
+        $crate::define_relation!(@relation $c * $b = $a);
+    };
+    (@relation $a:ident ^ 2 = $c:ident) => {
+        $crate::define_relation!(@check $a, $a, $c);
+        $crate::define_relation!(@mul $a, $a, $c);
+        $crate::define_relation!(@div $c, $a, $a);
+    };
+    ($($a:ident $op:tt $b:tt = $c:ident);* $(;)?) => {
+        $($crate::define_relation!(@relation $a $op $b = $c);)*
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    define_measurement! {
+        /// A luminous intensity, with a base unit of candelas.
+        LuminousIntensity = [luminous_intensity: 1]
+    }
+
+    fn square(value: f64) -> f64 {
+        value * value
+    }
+
+    define_units! { LuminousIntensity =>
+        candela: ("cd", 1.0, Si, "candela", "candelas"),
+        #[conversion(square, f64::sqrt)]
+        root_candela: ("√cd", 1.0, Other, "root candela", "root candelas"),
+    }
+
+    #[test]
+    fn conversion_functions() {
+        let intensity = LuminousIntensity::new::<root_candela>(3.0);
+        assert_eq!(intensity, LuminousIntensity(9.0));
+        assert_eq!(intensity.get::<root_candela>(), 3.0);
+        assert_eq!(LuminousIntensity::new::<candela>(16.0).get::<root_candela>(), 4.0);
+        assert_eq!(LuminousIntensity::parse("3 √cd"), Ok(intensity));
+        assert_eq!(intensity.to_string(), "9.00 cd");
+
+        let policy = DisplayPolicy::default().fix::<root_candela>();
+        assert_eq!(intensity.display_with(&policy).to_string(), "3.00 √cd");
+    }
 }
@@ -0,0 +1,24 @@
+use crate::{define_measurement, define_units};
+
+define_measurement! {
+    /// A measurement of magnetic flux density, with a base unit of teslas.
+    MagneticFluxDensity = [mass: 1, time: -2, current: -1]
+}
+
+define_units! { MagneticFluxDensity =>
+    #[si_prefixes]
+    tesla: ("T", 1.0, Si, "tesla", "teslas"),
+    gauss: ("G", 1e-4, Other, "gauss", "gauss"),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_works() {
+        let field = MagneticFluxDensity::new::<gauss>(0.5);
+        assert!((field.get::<microtesla>() - 50.0).abs() < 1e-9);
+        assert_eq!(MagneticFluxDensity::new::<tesla>(1.5).to_string(), "1.50 T");
+    }
+}
@@ -2,15 +2,16 @@ use crate::{define_measurement, define_units};
 
 define_measurement! {
     /// A measurement of mass, with a base unit of kilograms.
-    Mass
+    Mass = [mass: 1]
 }
 
 define_units! { Mass =>
-    kilogram: ("kg", 1.0),
-    gram: ("g", 0.001),
-    tonne: ("t", 1000.0),
-    earth_mass: ("M⊕", 5.972e24),
-    solar_mass: ("M☉", 1.989e30),
+    #[prefix(KILO)]
+    kilogram: ("kg", 1.0, Si, "kilogram", "kilograms"),
+    gram: ("g", 0.001, Si, "gram", "grams"),
+    tonne: ("t", 1000.0, Metric, "tonne", "tonnes"),
+    earth_mass: ("M⊕", 5.972e24, Astronomical, "Earth mass", "Earth masses"),
+    solar_mass: ("M☉", 1.989e30, Astronomical, "solar mass", "solar masses"),
 }
 
 #[cfg(test)]
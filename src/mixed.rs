@@ -0,0 +1,292 @@
+//! Formatting a measurement across several units, such as `"1 h 23 min 45 s"`.
+
+use std::{fmt, marker::PhantomData};
+
+use crate::{BesiUnit, Measurement, UnitDisplayInfo, format::pad};
+
+/// How the last component of a mixed display is rounded.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Rounding {
+    /// Round to the nearest value.
+    #[default]
+    Nearest,
+    /// Round towards zero.
+    Down,
+    /// Round away from zero.
+    Up,
+}
+
+impl Rounding {
+    fn apply(self, value: f64) -> f64 {
+        match self {
+            Self::Nearest => value.round(),
+            Self::Down => value.trunc(),
+            Self::Up => value.ceil(),
+        }
+    }
+}
+
+/// Describes how to break a measurement down across a descending list of units.
+///
+/// ```
+/// use besi::prelude::*;
+///
+/// let format = MixedFormat::new().unit::<hour>().unit::<minute>().unit::<second>();
+/// assert_eq!(Time(5025.0).display_mixed(&format).to_string(), "1 h 23 min 45 s");
+///
+/// let format = format.max_components(2);
+/// assert_eq!(Time(5025.0).display_mixed(&format).to_string(), "1 h 24 min");
+/// ```
+#[derive(Clone, Debug)]
+pub struct MixedFormat<M> {
+    units: Vec<&'static UnitDisplayInfo>,
+    max_components: Option<usize>,
+    rounding: Rounding,
+    precision: usize,
+    _measurement: PhantomData<M>,
+}
+
+impl<M: Measurement> Default for MixedFormat<M> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<M: Measurement> MixedFormat<M> {
+    /// Creates a format without any units.
+    pub fn new() -> Self {
+        Self {
+            units: Vec::new(),
+            max_components: None,
+            rounding: Rounding::Nearest,
+            precision: 0,
+            _measurement: PhantomData,
+        }
+    }
+
+    /// Adds the unit `U`. Units are always used from largest to smallest.
+    ///
+    /// # Panics
+    ///
+    /// If `U` has an offset, such as degrees Celsius, or a non-linear
+    /// [`Conversion`](crate::Conversion), such as kilometers per liter, since its values
+    /// can't be added up.
+    pub fn unit<U: BesiUnit<Measurement = M>>(mut self) -> Self {
+        assert!(
+            U::OFFSET == 0.0,
+            "{} has an offset and can't be part of a mixed format",
+            U::SYMBOL
+        );
+        assert!(
+            U::CONVERSION.is_linear(),
+            "{} isn't linear and can't be part of a mixed format",
+            U::SYMBOL
+        );
+        self.units.push(UnitDisplayInfo::of::<U>());
+        self.units
+            .sort_by(|a, b| b.scale_factor.total_cmp(&a.scale_factor));
+        self
+    }
+
+    /// Shows at most `count` components, starting from the largest non-zero one.
+    pub fn max_components(mut self, count: usize) -> Self {
+        self.max_components = Some(count.max(1));
+        self
+    }
+
+    /// Sets how the last component is rounded.
+    pub fn rounding(mut self, rounding: Rounding) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Sets the number of decimals of the last component (zero by default).
+    ///
+    /// A precision given to the formatter (`{:.2}`) takes priority.
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Splits the magnitude of `value` (in base units) into `(count, unit)` components.
+    ///
+    /// Every count but the last is a whole number. Zero components are left out,
+    /// unless the whole value rounds to zero.
+    pub fn components(&self, value: f64, precision: usize) -> Vec<(f64, &'static UnitDisplayInfo)> {
+        let value = value.abs();
+        let count = self.max_components.unwrap_or(usize::MAX);
+        let mut first = self
+            .units
+            .iter()
+            .position(|unit| value >= unit.scale_factor)
+            .unwrap_or(self.units.len().saturating_sub(1));
+
+        // Work in whole multiples of the last component's resolution, so rounding carries over.
+        let (units, quantum, mut remaining) = loop {
+            let units: Vec<_> = self.units.iter().skip(first).take(count).collect();
+            let Some(last) = units.last() else {
+                return Vec::new();
+            };
+            let quantum = last.scale_factor / 10f64.powi(precision as i32);
+            let remaining = self.rounding.apply(value / quantum);
+            // Rounding up can make the next larger unit the leading one.
+            if first > 0 && remaining * quantum >= self.units[first - 1].scale_factor {
+                first -= 1;
+                continue;
+            }
+            break (units, quantum, remaining);
+        };
+
+        let mut components = Vec::new();
+        for (i, unit) in units.iter().enumerate() {
+            let count = if i + 1 == units.len() {
+                remaining * quantum / unit.scale_factor
+            } else {
+                let per_unit = unit.scale_factor / quantum;
+                let count = (remaining / per_unit + 1e-9).floor();
+                remaining = (remaining - count * per_unit).max(0.0);
+                count
+            };
+            if count != 0.0 {
+                components.push((count, **unit));
+            }
+        }
+
+        if components.is_empty() {
+            components.push((0.0, *units[units.len() - 1]));
+        }
+        components
+    }
+}
+
+/// Displays a measurement according to a [`MixedFormat`].
+///
+/// Created by the `display_mixed` method of every measurement. The alternate
+/// flag (`{:#}`) uses long unit names.
+pub struct MixedDisplay<'a, M> {
+    value: M,
+    format: &'a MixedFormat<M>,
+}
+
+impl<'a, M: Measurement> MixedDisplay<'a, M> {
+    pub fn new(value: M, format: &'a MixedFormat<M>) -> Self {
+        Self { value, format }
+    }
+}
+
+impl<M: Measurement> fmt::Display for MixedDisplay<'_, M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = self.value.to_base();
+        let precision = f.precision().unwrap_or(self.format.precision);
+        let components = self.format.components(value, precision);
+
+        let mut text = String::new();
+        if value < 0.0 && components.iter().any(|(count, _)| *count != 0.0) {
+            text.push('-');
+        } else if f.sign_plus() {
+            text.push('+');
+        }
+
+        for (i, (count, unit)) in components.iter().enumerate() {
+            if i > 0 {
+                text.push(' ');
+            }
+            let digits = if i + 1 == components.len() {
+                precision
+            } else {
+                0
+            };
+            let number = format!("{count:.digits$}");
+            let name = if !f.alternate() {
+                unit.symbol
+            } else if number == "1" {
+                unit.singular
+            } else {
+                unit.plural
+            };
+            text.push_str(&format!("{number} {name}"));
+        }
+
+        pad(f, &text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn hms() -> MixedFormat<Time> {
+        MixedFormat::new()
+            .unit::<second>()
+            .unit::<hour>()
+            .unit::<minute>()
+    }
+
+    #[test]
+    fn breaks_down_values() {
+        assert_eq!(
+            Time(5025.0).display_mixed(&hms()).to_string(),
+            "1 h 23 min 45 s"
+        );
+        assert_eq!(Time(3605.0).display_mixed(&hms()).to_string(), "1 h 5 s");
+        assert_eq!(Time(-90.0).display_mixed(&hms()).to_string(), "-1 min 30 s");
+        assert_eq!(Time(0.2).display_mixed(&hms()).to_string(), "0 s");
+
+        let format = MixedFormat::new().unit::<kilometer>().unit::<meter>();
+        assert_eq!(
+            Length(3250.0).display_mixed(&format).to_string(),
+            "3 km 250 m"
+        );
+    }
+
+    #[test]
+    fn limits_and_rounds_components() {
+        let format = hms().max_components(2);
+        assert_eq!(
+            Time(5025.0).display_mixed(&format).to_string(),
+            "1 h 24 min"
+        );
+        assert_eq!(Time(45.0).display_mixed(&format).to_string(), "45 s");
+
+        let format = format.rounding(Rounding::Down);
+        assert_eq!(
+            Time(5025.0).display_mixed(&format).to_string(),
+            "1 h 23 min"
+        );
+
+        // Rounding carries into the larger units.
+        assert_eq!(Time(3599.6).display_mixed(&hms()).to_string(), "1 h");
+    }
+
+    #[test]
+    fn precision_and_long_names() {
+        assert_eq!(
+            format!("{:.1}", Time(61.26).display_mixed(&hms())),
+            "1 min 1.3 s"
+        );
+        assert_eq!(
+            format!("{:#}", Time(3660.0).display_mixed(&hms())),
+            "1 hour 1 minute"
+        );
+        assert_eq!(
+            format!("{:#}", Time(7320.0).display_mixed(&hms())),
+            "2 hours 2 minutes"
+        );
+        assert_eq!(
+            format!("{:>12}", Time(90.0).display_mixed(&hms())),
+            "  1 min 30 s"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "°C has an offset")]
+    fn rejects_offset_units() {
+        let _ = MixedFormat::<Temperature>::new().unit::<degree_celsius>();
+    }
+
+    #[test]
+    #[should_panic(expected = "km/L isn't linear")]
+    fn rejects_non_linear_units() {
+        let _ = MixedFormat::<FuelConsumption>::new().unit::<kilometers_per_liter>();
+    }
+}
@@ -0,0 +1,204 @@
+use std::{error::Error, fmt};
+
+use crate::UnitDisplayInfo;
+
+/// The error returned when a measurement cannot be parsed from a string.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseMeasurementError {
+    /// The input was empty or contained only whitespace.
+    Empty,
+    /// The numeric part of the input is not a valid finite number.
+    MalformedNumber(String),
+    /// A number was given without a unit.
+    MissingUnit,
+    /// The unit is not registered for any measurement.
+    UnknownUnit(String),
+    /// The unit is registered, but for a different measurement (e.g. `"5 kg"` given to `Length`).
+    WrongMeasurement {
+        /// The unit as it appeared in the input.
+        unit: String,
+        /// The measurement that was being parsed.
+        expected: &'static str,
+        /// The measurement the unit actually belongs to.
+        found: &'static str,
+    },
+    /// The value is outside the range of a non-linear unit, as in `"0 km/L"`.
+    OutOfRange(String),
+}
+
+impl fmt::Display for ParseMeasurementError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "cannot parse a measurement from an empty string"),
+            Self::MalformedNumber(number) => write!(f, "malformed number `{number}`"),
+            Self::MissingUnit => write!(f, "missing unit"),
+            Self::UnknownUnit(unit) => write!(f, "unknown unit `{unit}`"),
+            Self::WrongMeasurement {
+                unit,
+                expected,
+                found,
+            } => write!(
+                f,
+                "unit `{unit}` is a {found} unit, expected a {expected} unit"
+            ),
+            Self::OutOfRange(input) => write!(f, "`{input}` is outside the range of its unit"),
+        }
+    }
+}
+
+impl Error for ParseMeasurementError {}
+
+/// Parses a string such as `"1.5 km"` into a value in the base unit of `measurement`.
+///
+/// The number and unit may be separated by whitespace (`"-250 m"`) or written
+/// together (`"3e8m/s"`). The unit is resolved against the registered [`UnitDisplayInfo`]s.
+pub fn parse_measurement(measurement: &'static str, s: &str) -> Result<f64, ParseMeasurementError> {
+    let s = s.trim();
+    if s.is_empty() {
+        return Err(ParseMeasurementError::Empty);
+    }
+
+    let (number, unit) = split_number(s);
+    // Infinities and NaN can't be compared or converted, so they aren't measurements.
+    let value = number
+        .parse()
+        .ok()
+        .filter(|value: &f64| value.is_finite())
+        .ok_or_else(|| ParseMeasurementError::MalformedNumber(number.to_string()))?;
+
+    if unit.is_empty() {
+        return Err(ParseMeasurementError::MissingUnit);
+    }
+
+    let info = find_unit(measurement, unit)?;
+    info.try_to_base(value)
+        .ok_or_else(|| ParseMeasurementError::OutOfRange(s.to_string()))
+}
+
+/// Splits the input into its numeric and unit parts.
+fn split_number(s: &str) -> (&str, &str) {
+    if let Some((number, unit)) = s.split_once(char::is_whitespace) {
+        return (number, unit.trim());
+    }
+
+    let bytes = s.as_bytes();
+    let digits = |mut i: usize| {
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        i
+    };
+
+    let mut end = 0;
+    if matches!(bytes.first(), Some(b'+' | b'-')) {
+        end += 1;
+    }
+    end = digits(end);
+    if bytes.get(end) == Some(&b'.') {
+        end = digits(end + 1);
+    }
+    if matches!(bytes.get(end), Some(b'e' | b'E')) {
+        let mut exponent = end + 1;
+        if matches!(bytes.get(exponent), Some(b'+' | b'-')) {
+            exponent += 1;
+        }
+        if bytes.get(exponent).is_some_and(u8::is_ascii_digit) {
+            end = digits(exponent);
+        }
+    }
+
+    if end == 0 {
+        (s, "")
+    } else {
+        (&s[..end], &s[end..])
+    }
+}
+
+/// Finds the unit with the given symbol for `measurement`.
+pub(crate) fn find_unit(
+    measurement: &'static str,
+    symbol: &str,
+) -> Result<&'static UnitDisplayInfo, ParseMeasurementError> {
+    let mut other = None;
+    for unit in inventory::iter::<UnitDisplayInfo> {
+        if unit.symbol != symbol {
+            continue;
+        }
+        if unit.measurement_type_name == measurement {
+            return Ok(unit);
+        }
+        other.get_or_insert(unit.measurement_type_name);
+    }
+
+    match other {
+        Some(found) => Err(ParseMeasurementError::WrongMeasurement {
+            unit: symbol.to_string(),
+            expected: measurement,
+            found,
+        }),
+        None => Err(ParseMeasurementError::UnknownUnit(symbol.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn parses_with_and_without_whitespace() {
+        assert_eq!("1.5 km".parse::<Length>(), Ok(Length(1500.0)));
+        assert_eq!("-250 m".parse::<Length>(), Ok(Length(-250.0)));
+        assert_eq!(Length::parse("  20cm "), Ok(Length(0.2)));
+        assert_eq!("3e8 m/s".parse::<Velocity>(), Ok(Velocity(3e8)));
+        assert_eq!("3e8m/s".parse::<Velocity>(), Ok(Velocity(3e8)));
+    }
+
+    #[test]
+    fn reports_errors() {
+        assert_eq!(Length::parse(""), Err(ParseMeasurementError::Empty));
+        assert_eq!(Length::parse("12"), Err(ParseMeasurementError::MissingUnit));
+        assert_eq!(
+            Length::parse("1.2.3 km"),
+            Err(ParseMeasurementError::MalformedNumber("1.2.3".to_string()))
+        );
+        assert_eq!(
+            Length::parse("km"),
+            Err(ParseMeasurementError::MalformedNumber("km".to_string()))
+        );
+        for (input, number) in [
+            ("NaN m", "NaN"),
+            ("inf m", "inf"),
+            ("-inf m", "-inf"),
+            ("1e999 m", "1e999"),
+        ] {
+            assert_eq!(
+                Length::parse(input),
+                Err(ParseMeasurementError::MalformedNumber(number.to_string()))
+            );
+        }
+        assert_eq!(
+            Length::parse("5 furlongs"),
+            Err(ParseMeasurementError::UnknownUnit("furlongs".to_string()))
+        );
+        assert_eq!(
+            Length::parse("5 kg"),
+            Err(ParseMeasurementError::WrongMeasurement {
+                unit: "kg".to_string(),
+                expected: "Length",
+                found: "Mass",
+            })
+        );
+        for input in ["0 km/L", "-5 km/L", "0 mpg"] {
+            assert_eq!(
+                input.parse::<FuelConsumption>(),
+                Err(ParseMeasurementError::OutOfRange(input.to_string()))
+            );
+        }
+        assert_eq!(
+            Power::parse("1e6 dBm").unwrap_err().to_string(),
+            "`1e6 dBm` is outside the range of its unit"
+        );
+        assert!(Power::parse("-10 dBm").is_ok());
+    }
+}
@@ -1,5 +1,6 @@
 use crate::{
     angle::*,
+    area::Area,
     length::{Length, LengthUnit},
 };
 use bevy_math::{DVec3, prelude::*};
@@ -86,15 +87,13 @@ impl DPos3 {
 
     /// Calculates the squared magnitude of the position vector.
     /// This is faster than `length()` as it avoids a square root.
-    /// The result is a raw f64 representing the squared base units (meters squared).
-    // Note: A more advanced implementation might return a new `Area` measurement type.
-    pub fn length_squared(&self) -> f64 {
-        self.x.0.powi(2) + self.y.0.powi(2) + self.z.0.powi(2)
+    pub fn length_squared(&self) -> Area {
+        self.dot(*self)
     }
 
     /// Calculates the magnitude (or length) of the position vector.
     pub fn length(&self) -> Length {
-        Length(self.length_squared().sqrt())
+        self.length_squared().sqrt()
     }
 
     /// Returns a unitless `DVec3` representing the direction of this position vector.
@@ -108,26 +107,31 @@ impl DPos3 {
     }
 
     /// Calculates the dot product of two position vectors.
-    /// The result is a raw f64 representing the squared base units (meters squared).
-    pub fn dot(&self, rhs: Self) -> f64 {
-        self.x.0 * rhs.x.0 + self.y.0 * rhs.y.0 + self.z.0 * rhs.z.0
+    pub fn dot(&self, rhs: Self) -> Area {
+        self.x * rhs.x + self.y * rhs.y + self.z * rhs.z
     }
 
     /// Calculates the cross product of two position vectors.
-    /// The result is a new `DPos3` where the components are in squared units,
-    /// which isn't physically standard. This is a pragmatic choice for geometric calculations.
-    pub fn cross(&self, rhs: Self) -> DPos3 {
-        DPos3::from_lengths(
-            Length(self.y.0 * rhs.z.0 - self.z.0 * rhs.y.0),
-            Length(self.z.0 * rhs.x.0 - self.x.0 * rhs.z.0),
-            Length(self.x.0 * rhs.y.0 - self.y.0 * rhs.x.0),
-        )
+    /// Each component is an area, such as the area of the parallelogram the vectors
+    /// span projected onto the plane normal to that axis.
+    pub fn cross(&self, rhs: Self) -> [Area; 3] {
+        [
+            self.y * rhs.z - self.z * rhs.y,
+            self.z * rhs.x - self.x * rhs.z,
+            self.x * rhs.y - self.y * rhs.x,
+        ]
     }
 }
 
 impl fmt::Display for DPos3 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+        let precision = f.precision().unwrap_or(crate::format::DEFAULT_PRECISION);
+        let text = if f.sign_plus() {
+            format!("({:+.precision$}, {:+.precision$}, {:+.precision$})", self.x, self.y, self.z)
+        } else {
+            format!("({:.precision$}, {:.precision$}, {:.precision$})", self.x, self.y, self.z)
+        };
+        crate::format::pad(f, &text)
     }
 }
 
@@ -236,3 +240,19 @@ impl LatLon {
         lon: Angle::ZERO,
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::length::meter;
+
+    #[test]
+    fn products_are_areas() {
+        let a = DPos3::new::<meter>(1.0, 2.0, 2.0);
+        let b = DPos3::new::<meter>(0.0, 1.0, 0.0);
+        assert_eq!(a.length_squared(), Area(9.0));
+        assert_eq!(a.length(), Length(3.0));
+        assert_eq!(a.dot(b), Area(2.0));
+        assert_eq!(a.cross(b), [Area(-2.0), Area(0.0), Area(1.0)]);
+    }
+}
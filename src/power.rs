@@ -2,26 +2,51 @@ use crate::{define_measurement, define_units};
 
 define_measurement! {
     /// A measurement of power, with a base unit of watts.
-    Power
+    Power = [mass: 1, length: 2, time: -3]
 }
 
 define_units! { Power =>
-    watt: ("W", 1.0),
-    kilowatt: ("kW", 1000.0),
-    megawatt: ("MW", 1.0e6),
-    terawatt: ("TW", 1.0e9),
-    gigawatt: ("GW", 1.0e12),
-    exawatt: ("EW", 1.0e15),
-    solar_luminosity: ("L☉", 3.846e26),
+    #[si_prefixes]
+    watt: ("W", 1.0, Si, "watt", "watts"),
+    solar_luminosity: ("L☉", 3.846e26, Astronomical, "solar luminosity", "solar luminosities"),
+    #[logarithmic(10.0)]
+    decibel_watt: ("dBW", 1.0, Other, "decibel-watt", "decibel-watts"),
+    #[logarithmic(10.0)]
+    decibel_milliwatt: ("dBm", 1e-3, Other, "decibel-milliwatt", "decibel-milliwatts"),
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::BesiUnit;
 
     #[test]
     fn it_works() {
         let power = Power::new::<kilowatt>(1.0);
         assert_eq!(power.as_ref(), &1000.0);
     }
+
+    #[test]
+    fn si_prefixes() {
+        assert_eq!(Power::new::<gigawatt>(1.0), Power(1.0e9));
+        assert_eq!(Power::new::<terawatt>(1.0), Power(1.0e12));
+        assert_eq!(Power::new::<milliwatt>(1.0), Power(1.0e-3));
+        assert_eq!(<quettawatt as BesiUnit>::SYMBOL, "QW");
+        assert_eq!(<microwatt as BesiUnit>::SYMBOL, "µW");
+        assert_eq!("2 GW".parse::<Power>(), Ok(Power(2.0e9)));
+        assert_eq!(format!("{:#}", Power(2.0e12)), "2 terawatts");
+    }
+
+    #[test]
+    fn decibels() {
+        assert_eq!(Power::new::<decibel_watt>(30.0), Power(1000.0));
+        assert!((Power::new::<decibel_milliwatt>(30.0).get::<watt>() - 1.0).abs() < 1e-12);
+        assert!((Power::new::<watt>(2.0).get::<decibel_milliwatt>() - 33.0103).abs() < 1e-4);
+
+        // Decibels are only displayed when fixed.
+        let power: Power = "20 dBm".parse().unwrap();
+        assert_eq!(power.to_string(), "100.00 mW");
+        let policy = crate::display::DisplayPolicy::default().fix::<decibel_milliwatt>();
+        assert_eq!(power.display_with(&policy).to_string(), "20.00 dBm");
+    }
 }
@@ -0,0 +1,83 @@
+//! The SI prefixes, from quecto (10⁻³⁰) to quetta (10³⁰).
+//!
+//! Units declared with `#[si_prefixes]` in [`define_units!`](crate::define_units) get one
+//! generated unit per prefix, such as `kilowatt` and `milliwatt` for `watt`.
+
+/// An SI prefix such as kilo (`k`, 10³).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SiPrefix {
+    /// The symbol of the prefix, e.g. `"k"`.
+    pub symbol: &'static str,
+    /// The name of the prefix, e.g. `"kilo"`.
+    pub name: &'static str,
+    /// The power of ten the prefix stands for, e.g. `3`.
+    pub exponent: i32,
+}
+
+macro_rules! prefixes {
+    ($($constant:ident: ($symbol:literal, $name:literal, $exponent:literal)),* $(,)?) => {
+        impl SiPrefix {
+            $(
+                #[doc = concat!("`", $symbol, "`, 10^", stringify!($exponent))]
+                pub const $constant: Self = Self {
+                    symbol: $symbol,
+                    name: $name,
+                    exponent: $exponent,
+                };
+            )*
+
+            /// Every SI prefix, from the smallest to the largest.
+            pub const ALL: &'static [Self] = &[$(Self::$constant),*];
+        }
+    };
+}
+
+prefixes! {
+    QUECTO: ("q", "quecto", -30),
+    RONTO: ("r", "ronto", -27),
+    YOCTO: ("y", "yocto", -24),
+    ZEPTO: ("z", "zepto", -21),
+    ATTO: ("a", "atto", -18),
+    FEMTO: ("f", "femto", -15),
+    PICO: ("p", "pico", -12),
+    NANO: ("n", "nano", -9),
+    MICRO: ("µ", "micro", -6),
+    MILLI: ("m", "milli", -3),
+    CENTI: ("c", "centi", -2),
+    DECI: ("d", "deci", -1),
+    DECA: ("da", "deca", 1),
+    HECTO: ("h", "hecto", 2),
+    KILO: ("k", "kilo", 3),
+    MEGA: ("M", "mega", 6),
+    GIGA: ("G", "giga", 9),
+    TERA: ("T", "tera", 12),
+    PETA: ("P", "peta", 15),
+    EXA: ("E", "exa", 18),
+    ZETTA: ("Z", "zetta", 21),
+    YOTTA: ("Y", "yotta", 24),
+    RONNA: ("R", "ronna", 27),
+    QUETTA: ("Q", "quetta", 30),
+}
+
+impl SiPrefix {
+    /// The factor the prefix multiplies a unit by, e.g. `1000.0` for kilo.
+    pub fn factor(&self) -> f64 {
+        10f64.powi(self.exponent)
+    }
+
+    /// Whether the exponent is a multiple of three, which excludes centi, deci, deca and hecto.
+    ///
+    /// Only these prefixes are picked by `Display` unless the
+    /// [`DisplayPolicy`](crate::display::DisplayPolicy) allows all of them.
+    pub fn is_engineering(&self) -> bool {
+        self.exponent % 3 == 0
+    }
+
+    /// Finds the prefix for the given exponent.
+    pub fn from_exponent(exponent: i32) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .find(|prefix| prefix.exponent == exponent)
+            .copied()
+    }
+}
@@ -2,14 +2,14 @@ use crate::{define_measurement, define_units};
 
 define_measurement! {
     /// A measurement of pressure, with a base unit of pascals.
-    Pressure
+    Pressure = [mass: 1, length: -1, time: -2]
 }
 
 define_units! { Pressure =>
-    pascal: ("Pa", 1.0),
-    kilopascal: ("kPa", 1000.0),
-    bar: ("bar", 100_000.0),
-    atmosphere: ("atm", 1.013_25_E5),
+    #[si_prefixes]
+    pascal: ("Pa", 1.0, Si, "pascal", "pascals"),
+    bar: ("bar", 100_000.0, Metric, "bar", "bars"),
+    atmosphere: ("atm", 1.013_25_E5, Other, "atmosphere", "atmospheres"),
 }
 
 #[cfg(test)]
@@ -0,0 +1,631 @@
+//! Quantities whose measurement is only known at runtime.
+//!
+//! A [`DynQuantity`] is a base-unit value with a [`Dimension`]. Adding or subtracting
+//! quantities checks their dimensions, while multiplying and dividing combines them
+//! (and fails for absolute quantities, such as temperatures). Every measurement converts
+//! into a `DynQuantity`, and back with `TryFrom`:
+//!
+//! ```
+//! use besi::prelude::*;
+//!
+//! let distance = DynQuantity::from(Length::new::<kilometer>(3.0));
+//! let duration = DynQuantity::from(Time::new::<minute>(20.0));
+//! let speed = (distance / duration).unwrap();
+//! assert_eq!(Velocity::try_from(speed), Ok(Velocity(2.5)));
+//!
+//! assert!(Force::try_from(speed).is_err());
+//! assert!((distance + duration).is_err());
+//! ```
+
+use std::{
+    error::Error,
+    fmt,
+    ops::{Div, Mul, Neg},
+};
+
+use crate::{Measurement, UnitDisplayInfo, display::DisplayPolicy};
+
+/// The exponents of the base dimensions of a measurement, such as `length: 1, time: -1`
+/// for velocity.
+///
+/// Angles count as a dimension, which keeps torque (N·m per radian) apart from energy.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Dimension {
+    pub length: i8,
+    pub mass: i8,
+    pub time: i8,
+    pub current: i8,
+    pub temperature: i8,
+    pub amount: i8,
+    pub luminous_intensity: i8,
+    pub angle: i8,
+}
+
+impl Dimension {
+    /// The dimension of plain numbers.
+    pub const NONE: Self = Self {
+        length: 0,
+        mass: 0,
+        time: 0,
+        current: 0,
+        temperature: 0,
+        amount: 0,
+        luminous_intensity: 0,
+        angle: 0,
+    };
+
+    /// The base dimensions in order, with the symbol of their SI unit.
+    fn exponents(&self) -> [(i8, &'static str); 8] {
+        [
+            (self.mass, "kg"),
+            (self.length, "m"),
+            (self.time, "s"),
+            (self.current, "A"),
+            (self.temperature, "K"),
+            (self.amount, "mol"),
+            (self.luminous_intensity, "cd"),
+            (self.angle, "rad"),
+        ]
+    }
+
+    const fn combine(self, other: Self, sign: i8) -> Self {
+        Self {
+            length: self.length + sign * other.length,
+            mass: self.mass + sign * other.mass,
+            time: self.time + sign * other.time,
+            current: self.current + sign * other.current,
+            temperature: self.temperature + sign * other.temperature,
+            amount: self.amount + sign * other.amount,
+            luminous_intensity: self.luminous_intensity + sign * other.luminous_intensity,
+            angle: self.angle + sign * other.angle,
+        }
+    }
+
+    /// The dimension of a product.
+    pub const fn mul(self, other: Self) -> Self {
+        self.combine(other, 1)
+    }
+
+    /// The dimension of a quotient.
+    pub const fn div(self, other: Self) -> Self {
+        self.combine(other, -1)
+    }
+
+    /// The dimension raised to the power `n`.
+    pub const fn powi(self, n: i8) -> Self {
+        Self::NONE.combine(self, n)
+    }
+
+    fn checked_combine(self, other: Self, sign: i8) -> Option<Self> {
+        let axis = |a: i8, b: i8| a.checked_add(sign.checked_mul(b)?);
+        Some(Self {
+            length: axis(self.length, other.length)?,
+            mass: axis(self.mass, other.mass)?,
+            time: axis(self.time, other.time)?,
+            current: axis(self.current, other.current)?,
+            temperature: axis(self.temperature, other.temperature)?,
+            amount: axis(self.amount, other.amount)?,
+            luminous_intensity: axis(self.luminous_intensity, other.luminous_intensity)?,
+            angle: axis(self.angle, other.angle)?,
+        })
+    }
+
+    /// The dimension of a product, or `None` if an exponent leaves the range of `i8`.
+    pub fn checked_mul(self, other: Self) -> Option<Self> {
+        self.checked_combine(other, 1)
+    }
+
+    /// The dimension of a quotient, or `None` if an exponent leaves the range of `i8`.
+    pub fn checked_div(self, other: Self) -> Option<Self> {
+        self.checked_combine(other, -1)
+    }
+
+    /// The dimension raised to the power `n`, or `None` if an exponent leaves the range of `i8`.
+    pub fn checked_powi(self, n: i8) -> Option<Self> {
+        Self::NONE.checked_combine(self, n)
+    }
+
+    /// Compares two dimensions in const contexts, where `==` isn't available.
+    #[doc(hidden)]
+    pub const fn const_eq(self, other: Self) -> bool {
+        self.length == other.length
+            && self.mass == other.mass
+            && self.time == other.time
+            && self.current == other.current
+            && self.temperature == other.temperature
+            && self.amount == other.amount
+            && self.luminous_intensity == other.luminous_intensity
+            && self.angle == other.angle
+    }
+
+    /// Whether this is the dimension of plain numbers.
+    pub fn is_dimensionless(&self) -> bool {
+        *self == Self::NONE
+    }
+
+    /// The registered measurements with this dimension, in alphabetical order.
+    pub fn measurements(&self) -> Vec<&'static str> {
+        let mut names: Vec<_> = inventory::iter::<UnitDisplayInfo>
+            .into_iter()
+            .filter(|unit| unit.dimension == *self)
+            .map(|unit| unit.measurement_type_name)
+            .collect();
+        names.sort_unstable();
+        names.dedup();
+        names
+    }
+}
+
+/// Written with SI base units, e.g. `"kg·m·s⁻²"`.
+impl fmt::Display for Dimension {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut first = true;
+        for (exponent, symbol) in self.exponents() {
+            if exponent == 0 {
+                continue;
+            }
+            if !first {
+                f.write_str("·")?;
+            }
+            first = false;
+            f.write_str(symbol)?;
+            if exponent != 1 {
+                f.write_str(&superscript(exponent))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn superscript(n: i8) -> String {
+    const DIGITS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+    let sign = if n < 0 { "⁻" } else { "" };
+    let digits: String = n
+        .unsigned_abs()
+        .to_string()
+        .bytes()
+        .map(|digit| DIGITS[(digit - b'0') as usize])
+        .collect();
+    format!("{sign}{digits}")
+}
+
+impl Mul for Dimension {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        Dimension::mul(self, rhs)
+    }
+}
+
+impl Div for Dimension {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self::Output {
+        Dimension::div(self, rhs)
+    }
+}
+
+/// Whether a quantity is an amount, or a point on an affine scale whose zero is arbitrary.
+///
+/// Absolute temperatures are points: 20 °C plus 20 °C means nothing, but their difference
+/// is an amount (a `TemperatureDifference`), and an amount can be added to a point.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum QuantityKind {
+    /// An amount, which adds and scales like a plain number.
+    #[default]
+    Amount,
+    /// A point on an affine scale, such as an absolute temperature.
+    Absolute,
+    /// An amount of the named measurement, which shares its dimension with another one
+    /// without being interchangeable with it, as fuel consumption does with area.
+    Distinct(&'static str),
+}
+
+/// The registered measurements with `dimension` and `kind`, in alphabetical order.
+fn measurements_of(dimension: Dimension, kind: QuantityKind) -> Vec<&'static str> {
+    let mut names: Vec<_> = inventory::iter::<UnitDisplayInfo>
+        .into_iter()
+        .filter(|unit| unit.dimension == dimension && unit.kind == kind)
+        .map(|unit| unit.measurement_type_name)
+        .collect();
+    names.sort_unstable();
+    names.dedup();
+    names
+}
+
+/// The error returned when quantities of different dimensions (or kinds) are combined
+/// or converted.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DimensionMismatchError {
+    /// The dimension that was required.
+    pub expected: Dimension,
+    /// The dimension that was given.
+    pub found: Dimension,
+    /// The kind that was required.
+    pub expected_kind: QuantityKind,
+    /// The kind that was given.
+    pub found_kind: QuantityKind,
+}
+
+impl fmt::Display for DimensionMismatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mismatch = if self.expected == self.found { "kind" } else { "dimension" };
+        write!(
+            f,
+            "{mismatch} mismatch: expected {}, found {}",
+            Described(self.expected, self.expected_kind),
+            Described(self.found, self.found_kind)
+        )
+    }
+}
+
+impl Error for DimensionMismatchError {}
+
+/// The error returned when quantities can't be multiplied, divided or scaled.
+#[derive(Debug, Clone, PartialEq)]
+pub enum QuantityError {
+    /// An absolute quantity of the given dimension, such as a temperature, was scaled,
+    /// negated or multiplied. Only its differences can be.
+    Absolute(Dimension),
+    /// An exponent of the resulting dimension doesn't fit in an `i8`, as in `m¹⁰⁰ · m¹⁰⁰`.
+    ExponentOverflow,
+}
+
+impl fmt::Display for QuantityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Absolute(dimension) => write!(
+                f,
+                "an absolute {} can't be scaled, negated or multiplied",
+                Described(*dimension, QuantityKind::Absolute)
+            ),
+            Self::ExponentOverflow => write!(f, "dimension exponent out of range"),
+        }
+    }
+}
+
+impl Error for QuantityError {}
+
+/// Describes a dimension by its measurement name where there is one.
+struct Described(Dimension, QuantityKind);
+
+impl fmt::Display for Described {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match measurements_of(self.0, self.1).first() {
+            Some(name) if self.0.is_dimensionless() => write!(f, "{name}"),
+            Some(name) => write!(f, "{name} ({})", self.0),
+            None if self.0.is_dimensionless() => write!(f, "a plain number"),
+            None => write!(f, "{}", self.0),
+        }
+    }
+}
+
+/// A value in base units with a dimension checked at runtime.
+///
+/// Absolute quantities, such as temperatures, follow the rules of their [`QuantityKind`]:
+/// subtracting two of them gives an amount, and only amounts can be added to them.
+/// Distinct quantities only combine with their own measurement, and their multiples keep
+/// their kind. Absolute quantities can't be scaled, negated or multiplied, so these
+/// operators return a `Result` too.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct DynQuantity {
+    /// The value in the SI base units of the dimension.
+    pub value: f64,
+    /// The dimension of the value.
+    pub dimension: Dimension,
+    /// Whether the value is an amount or a point on an affine scale.
+    pub kind: QuantityKind,
+}
+
+impl DynQuantity {
+    /// Creates an amount from a value in base units.
+    pub const fn new(value: f64, dimension: Dimension) -> Self {
+        Self {
+            value,
+            dimension,
+            kind: QuantityKind::Amount,
+        }
+    }
+
+    /// Sets the kind of the quantity.
+    pub const fn with_kind(mut self, kind: QuantityKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Converts a static measurement.
+    pub fn of<M: Measurement>(measurement: M) -> Self {
+        Self::new(measurement.to_base(), M::DIMENSION).with_kind(M::KIND)
+    }
+
+    /// Converts to the measurement `M` if the dimensions and kinds match.
+    pub fn to<M: Measurement>(self) -> Result<M, DimensionMismatchError> {
+        if self.kind != M::KIND {
+            return Err(self.mismatch(M::DIMENSION, M::KIND));
+        }
+        self.expect(M::DIMENSION).map(|value| M::from_base(value))
+    }
+
+    /// Returns the value if the quantity has the given dimension.
+    pub fn expect(self, dimension: Dimension) -> Result<f64, DimensionMismatchError> {
+        if self.dimension == dimension {
+            Ok(self.value)
+        } else {
+            Err(self.mismatch(dimension, self.kind))
+        }
+    }
+
+    fn mismatch(self, expected: Dimension, expected_kind: QuantityKind) -> DimensionMismatchError {
+        DimensionMismatchError {
+            expected,
+            found: self.dimension,
+            expected_kind,
+            found_kind: self.kind,
+        }
+    }
+
+    /// The name of the registered measurement with this dimension and kind, if there is one.
+    pub fn measurement(&self) -> Option<&'static str> {
+        measurements_of(self.dimension, self.kind).first().copied()
+    }
+
+    /// Raises the quantity to the power `n`, which fails for absolute quantities and
+    /// exponents out of range.
+    pub fn powi(self, n: i8) -> Result<Self, QuantityError> {
+        self.amount()?;
+        let dimension = self.dimension.checked_powi(n).ok_or(QuantityError::ExponentOverflow)?;
+        Ok(Self::new(self.value.powi(n.into()), dimension))
+    }
+
+    /// Adds `rhs`, which must have the same dimension. At most one of the quantities may
+    /// be absolute, which makes the sum absolute.
+    pub fn try_add(self, rhs: Self) -> Result<Self, DimensionMismatchError> {
+        use QuantityKind::*;
+        let value = rhs.expect(self.dimension)?;
+        let kind = match (self.kind, rhs.kind) {
+            (Absolute, Absolute) => return Err(rhs.mismatch(self.dimension, Amount)),
+            (Absolute, Amount) | (Amount, Absolute) => Absolute,
+            (a, b) if a == b => a,
+            _ => return Err(rhs.mismatch(self.dimension, self.kind)),
+        };
+        Ok(Self::new(self.value + value, self.dimension).with_kind(kind))
+    }
+
+    /// Subtracts `rhs`, which must have the same dimension. The difference of two absolute
+    /// quantities is an amount, and an absolute quantity can't be subtracted from an amount.
+    pub fn try_sub(self, rhs: Self) -> Result<Self, DimensionMismatchError> {
+        use QuantityKind::*;
+        let value = rhs.expect(self.dimension)?;
+        let kind = match (self.kind, rhs.kind) {
+            (Amount, Absolute) => return Err(rhs.mismatch(self.dimension, Amount)),
+            (Absolute, Amount) => Absolute,
+            (Absolute, Absolute) => Amount,
+            (a, b) if a == b => a,
+            _ => return Err(rhs.mismatch(self.dimension, self.kind)),
+        };
+        Ok(Self::new(self.value - value, self.dimension).with_kind(kind))
+    }
+
+    /// A quantity with `value` and the same dimension and kind, unless it is absolute.
+    fn scaled(self, value: f64) -> Result<Self, QuantityError> {
+        self.amount()?;
+        Ok(Self::new(value, self.dimension).with_kind(self.kind))
+    }
+
+    /// Fails if the quantity is absolute, and so can't be scaled or multiplied.
+    fn amount(&self) -> Result<(), QuantityError> {
+        match self.kind {
+            QuantityKind::Absolute => Err(QuantityError::Absolute(self.dimension)),
+            _ => Ok(()),
+        }
+    }
+
+    fn is_number(&self) -> bool {
+        self.dimension.is_dimensionless() && self.kind == QuantityKind::Amount
+    }
+}
+
+/// Adding quantities checks their dimensions, so the result must be handled.
+impl std::ops::Add for DynQuantity {
+    type Output = Result<Self, DimensionMismatchError>;
+    fn add(self, rhs: Self) -> Self::Output {
+        self.try_add(rhs)
+    }
+}
+
+/// Subtracting quantities checks their dimensions, so the result must be handled.
+impl std::ops::Sub for DynQuantity {
+    type Output = Result<Self, DimensionMismatchError>;
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.try_sub(rhs)
+    }
+}
+
+/// Multiplying fails for absolute quantities and exponents out of range, so the result
+/// must be handled.
+impl Mul for DynQuantity {
+    type Output = Result<Self, QuantityError>;
+    fn mul(self, rhs: Self) -> Self::Output {
+        // Multiples of a distinct quantity, such as "2 * 5 L/100 km", keep its kind.
+        if rhs.is_number() {
+            self * rhs.value
+        } else if self.is_number() {
+            rhs * self.value
+        } else {
+            self.amount()?;
+            rhs.amount()?;
+            let dimension = self.dimension.checked_mul(rhs.dimension)
+                .ok_or(QuantityError::ExponentOverflow)?;
+            Ok(Self::new(self.value * rhs.value, dimension))
+        }
+    }
+}
+
+/// Dividing fails for absolute quantities and exponents out of range, so the result must
+/// be handled.
+impl Div for DynQuantity {
+    type Output = Result<Self, QuantityError>;
+    fn div(self, rhs: Self) -> Self::Output {
+        if rhs.is_number() {
+            self / rhs.value
+        } else {
+            self.amount()?;
+            rhs.amount()?;
+            let dimension = self.dimension.checked_div(rhs.dimension)
+                .ok_or(QuantityError::ExponentOverflow)?;
+            Ok(Self::new(self.value / rhs.value, dimension))
+        }
+    }
+}
+
+impl Mul<f64> for DynQuantity {
+    type Output = Result<Self, QuantityError>;
+    fn mul(self, rhs: f64) -> Self::Output {
+        self.scaled(self.value * rhs)
+    }
+}
+
+impl Div<f64> for DynQuantity {
+    type Output = Result<Self, QuantityError>;
+    fn div(self, rhs: f64) -> Self::Output {
+        self.scaled(self.value / rhs)
+    }
+}
+
+impl Neg for DynQuantity {
+    type Output = Result<Self, QuantityError>;
+    fn neg(self) -> Self::Output {
+        self.scaled(-self.value)
+    }
+}
+
+/// Uses the measurement's display unit if the dimension belongs to a registered
+/// measurement, and SI base units (`"2.00 kg·s⁻¹"`) otherwise.
+impl fmt::Display for DynQuantity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.measurement() {
+            Some(measurement) if !self.dimension.is_dimensionless() => {
+                let unit = DisplayPolicy::select_global(measurement, self.value);
+                crate::format::write_unit(f, unit.from_base(self.value), unit)
+            }
+            _ if self.dimension.is_dimensionless() => {
+                crate::format::pad(f, &crate::format::number(f, self.value))
+            }
+            _ => crate::format::write_value(f, self.value, &self.dimension.to_string()),
+        }
+    }
+}
+
+impl From<f64> for DynQuantity {
+    fn from(value: f64) -> Self {
+        Self::new(value, Dimension::NONE)
+    }
+}
+
+impl TryFrom<DynQuantity> for f64 {
+    type Error = DimensionMismatchError;
+
+    fn try_from(quantity: DynQuantity) -> Result<Self, Self::Error> {
+        quantity.to()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn combines_dimensions() {
+        let length = DynQuantity::from(Length(100.0));
+        let time = DynQuantity::from(Time(20.0));
+        assert_eq!(
+            Velocity::try_from((length / time).unwrap()),
+            Ok(Velocity(5.0))
+        );
+        assert_eq!(
+            Acceleration::try_from((length / time.powi(2).unwrap()).unwrap()),
+            Ok(Acceleration(0.25))
+        );
+
+        let force = DynQuantity::from(Mass(2.0)) * DynQuantity::from(Acceleration(3.0));
+        let force = force.unwrap();
+        assert_eq!(force.measurement(), Some("Force"));
+        assert_eq!(Force::try_from(force), Ok(Force(6.0)));
+        assert_eq!(f64::try_from((length / length).unwrap()), Ok(1.0));
+
+        let work = (DynQuantity::from(Force(2.0)) * length).unwrap();
+        assert_eq!(work.measurement(), Some("Energy"));
+        assert!(Torque::try_from(work).is_err());
+    }
+
+    #[test]
+    fn checks_addition() {
+        let length = DynQuantity::from(Length(100.0));
+        assert_eq!((length + length).unwrap().value, 200.0);
+        assert_eq!((length - (length * 2.0).unwrap()).unwrap().value, -100.0);
+
+        let error = (length + DynQuantity::from(Time(1.0))).unwrap_err();
+        assert_eq!(error.expected, Length::DIMENSION);
+        assert_eq!(
+            error.to_string(),
+            "dimension mismatch: expected Length (m), found Time (s)"
+        );
+    }
+
+    #[test]
+    fn display() {
+        let length = DynQuantity::from(Length(1500.0));
+        assert_eq!(length.to_string(), "1.50 km");
+        assert_eq!((length / length).unwrap().to_string(), "1.00");
+
+        let per_second = DynQuantity::from(Time(1.0)).powi(4).unwrap();
+        let odd = (DynQuantity::from(Mass(2.0)) / per_second).unwrap();
+        assert_eq!(odd.to_string(), "2.00 kg·s⁻⁴");
+        assert_eq!(Force::DIMENSION.to_string(), "kg·m·s⁻²");
+    }
+
+    #[test]
+    fn checks_exponents() {
+        let length = DynQuantity::from(Length(1.0));
+        let large = length.powi(100).unwrap();
+        assert_eq!(large.powi(2), Err(QuantityError::ExponentOverflow));
+        assert_eq!(large * large, Err(QuantityError::ExponentOverflow));
+        assert_eq!(large / large.powi(-1).unwrap(), Err(QuantityError::ExponentOverflow));
+        assert_eq!(length.powi(-128).unwrap().dimension.length, -128);
+        assert_eq!(Dimension::NONE.checked_div(length.powi(-128).unwrap().dimension), None);
+    }
+
+    #[test]
+    fn temperature() {
+        let temperature = DynQuantity::from(Temperature::new::<degree_celsius>(0.0));
+        assert_eq!(temperature.value, 273.15);
+        assert_eq!(Temperature::try_from(temperature), Ok(Temperature(273.15)));
+        assert!(Temperature::try_from(DynQuantity::from(Length(1.0))).is_err());
+        assert_eq!(temperature.measurement(), Some("Temperature"));
+
+        let difference = DynQuantity::of(TemperatureDifference(5.0));
+        assert_eq!(difference.measurement(), Some("TemperatureDifference"));
+        assert!(Temperature::try_from(difference).is_err());
+        assert!(TemperatureDifference::try_from(temperature).is_err());
+
+        assert!((temperature + temperature).is_err());
+        assert!((difference - temperature).is_err());
+        let warmer = (temperature + difference).unwrap();
+        assert_eq!(Temperature::try_from(warmer), Ok(Temperature(278.15)));
+        let change = (warmer - temperature).unwrap();
+        assert_eq!(change.kind, QuantityKind::Amount);
+        assert!((TemperatureDifference::try_from(change).unwrap().0 - 5.0).abs() < 1e-9);
+
+        assert_eq!(
+            temperature * 2.0,
+            Err(QuantityError::Absolute(Temperature::DIMENSION))
+        );
+        assert!((-temperature).is_err());
+        assert!((difference * temperature).is_err());
+        assert!(temperature.powi(2).is_err());
+        assert_eq!(
+            (-temperature).unwrap_err().to_string(),
+            "an absolute Temperature (K) can't be scaled, negated or multiplied"
+        );
+        assert!((-difference).is_ok());
+    }
+}
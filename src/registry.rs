@@ -0,0 +1,233 @@
+//! Queries over every registered unit.
+//!
+//! Units are registered by [`define_units!`](crate::define_units), so the registry also
+//! contains units defined outside of this crate. Results are sorted by measurement name,
+//! then by scale factor, then by offset, then by symbol.
+//!
+//! ```
+//! use besi::registry;
+//!
+//! assert!(registry::measurements().contains(&"Length"));
+//!
+//! let units = registry::units_of("Pressure");
+//! assert_eq!(units.first().map(|unit| unit.symbol), Some("qPa"));
+//!
+//! let meter = registry::find("Length", "m").unwrap();
+//! assert_eq!(meter.plural, "meters");
+//! assert_eq!(registry::find_by_name("Kilometers")[0].symbol, "km");
+//! ```
+
+use std::{cmp::Ordering, error::Error, fmt, ops::Deref};
+
+use crate::{BesiUnit, UnitDisplayInfo};
+
+/// A registered unit selected at runtime, for use with `new_dyn` and `get_dyn`.
+///
+/// ```
+/// use besi::prelude::*;
+///
+/// let unit = UnitHandle::find("Length", "km").unwrap();
+/// assert_eq!(Length::new_dyn(1.5, &unit), Ok(Length(1500.0)));
+/// assert_eq!(Length(500.0).get_dyn(&UnitHandle::of::<kilometer>()), Ok(0.5));
+/// assert!(Time(1.0).get_dyn(&unit).is_err());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct UnitHandle(&'static UnitDisplayInfo);
+
+impl UnitHandle {
+    /// The handle of the unit `U`.
+    pub fn of<U: BesiUnit>() -> Self {
+        Self(UnitDisplayInfo::of::<U>())
+    }
+
+    /// The handle of the unit of `measurement` with the given symbol.
+    pub fn find(measurement: &str, symbol: &str) -> Option<Self> {
+        find(measurement, symbol).map(Self)
+    }
+
+    /// The registered information of the unit.
+    pub fn info(&self) -> &'static UnitDisplayInfo {
+        self.0
+    }
+
+    /// Returns the unit's information if it belongs to `measurement`.
+    pub fn expect(
+        &self,
+        measurement: &'static str,
+    ) -> Result<&'static UnitDisplayInfo, UnitMismatchError> {
+        if self.0.measurement_type_name == measurement {
+            Ok(self.0)
+        } else {
+            Err(UnitMismatchError {
+                unit: self.0.symbol,
+                expected: measurement,
+                found: self.0.measurement_type_name,
+            })
+        }
+    }
+}
+
+impl From<&'static UnitDisplayInfo> for UnitHandle {
+    fn from(info: &'static UnitDisplayInfo) -> Self {
+        Self(info)
+    }
+}
+
+impl Deref for UnitHandle {
+    type Target = UnitDisplayInfo;
+
+    fn deref(&self) -> &UnitDisplayInfo {
+        self.0
+    }
+}
+
+/// The error returned when a [`UnitHandle`] is used with a measurement it doesn't belong to.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnitMismatchError {
+    /// The symbol of the unit.
+    pub unit: &'static str,
+    /// The measurement the unit was used with.
+    pub expected: &'static str,
+    /// The measurement the unit belongs to.
+    pub found: &'static str,
+}
+
+impl fmt::Display for UnitMismatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "unit `{}` is a {} unit, expected a {} unit",
+            self.unit, self.found, self.expected
+        )
+    }
+}
+
+impl Error for UnitMismatchError {}
+
+/// Every registered unit.
+pub fn units() -> Vec<&'static UnitDisplayInfo> {
+    sorted(inventory::iter::<UnitDisplayInfo>.into_iter())
+}
+
+/// Every registered unit of `measurement`, from the smallest to the largest.
+pub fn units_of(measurement: &str) -> Vec<&'static UnitDisplayInfo> {
+    sorted(UnitDisplayInfo::units_of(measurement))
+}
+
+/// The names of every measurement with registered units, in alphabetical order.
+pub fn measurements() -> Vec<&'static str> {
+    let mut names: Vec<_> = inventory::iter::<UnitDisplayInfo>
+        .into_iter()
+        .map(|unit| unit.measurement_type_name)
+        .collect();
+    names.sort_unstable();
+    names.dedup();
+    names
+}
+
+/// The unit of `measurement` with the given symbol.
+pub fn find(measurement: &str, symbol: &str) -> Option<&'static UnitDisplayInfo> {
+    UnitDisplayInfo::units_of(measurement).find(|unit| unit.symbol == symbol)
+}
+
+/// Every unit with the given symbol, which may be shared by several measurements
+/// (`"g"` is both the gram and standard gravity).
+pub fn find_by_symbol(symbol: &str) -> Vec<&'static UnitDisplayInfo> {
+    sorted(
+        inventory::iter::<UnitDisplayInfo>
+            .into_iter()
+            .filter(|unit| unit.symbol == symbol),
+    )
+}
+
+/// Every unit whose singular or plural long name is `name`, ignoring ASCII case.
+pub fn find_by_name(name: &str) -> Vec<&'static UnitDisplayInfo> {
+    sorted(
+        inventory::iter::<UnitDisplayInfo>
+            .into_iter()
+            .filter(|unit| {
+                unit.singular.eq_ignore_ascii_case(name) || unit.plural.eq_ignore_ascii_case(name)
+            }),
+    )
+}
+
+fn sorted(units: impl Iterator<Item = &'static UnitDisplayInfo>) -> Vec<&'static UnitDisplayInfo> {
+    let mut units: Vec<_> = units.collect();
+    units.sort_by(|a, b| compare(a, b));
+    units
+}
+
+fn compare(a: &UnitDisplayInfo, b: &UnitDisplayInfo) -> Ordering {
+    a.measurement_type_name
+        .cmp(b.measurement_type_name)
+        .then(a.scale_factor.total_cmp(&b.scale_factor))
+        .then(a.offset.total_cmp(&b.offset))
+        .then(a.symbol.cmp(b.symbol))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lists_sorted_units() {
+        let symbols: Vec<_> = units_of("Length").iter().map(|unit| unit.symbol).collect();
+        assert_eq!(symbols, ["cm", "m", "km", "R⊕", "R☉", "AU"]);
+
+        let all = units();
+        assert!(all.windows(2).all(|pair| compare(pair[0], pair[1]).is_le()));
+        assert_eq!(all, units());
+    }
+
+    #[test]
+    fn lists_measurements() {
+        let names = measurements();
+        assert!(names.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(names.contains(&"Temperature"));
+        assert!(names.contains(&"VolumeRate"));
+    }
+
+    #[test]
+    fn finds_units() {
+        assert_eq!(
+            find("Power", "kW").map(|unit| unit.scale_factor),
+            Some(1000.0)
+        );
+        assert!(find("Power", "km").is_none());
+
+        let measurements: Vec<_> = find_by_symbol("g")
+            .iter()
+            .map(|unit| unit.measurement_type_name)
+            .collect();
+        assert_eq!(measurements, ["Acceleration", "Mass"]);
+
+        let celsius = find_by_name("degree celsius");
+        assert_eq!(celsius.len(), 3);
+        assert_eq!(celsius[0].to_base(20.0), 293.15);
+        assert!(celsius[1..].iter().all(|unit| unit.to_base(20.0) == 20.0));
+    }
+
+    #[test]
+    fn converts_with_handles() {
+        use crate::prelude::*;
+
+        let unit = UnitHandle::from(find_by_name("kilometers per hour")[0]);
+        let speed = Velocity::new_dyn(36.0, &unit).unwrap();
+        assert!((speed.get::<meters_per_second>() - 10.0).abs() < 1e-9);
+        assert!((speed.get_dyn(&unit).unwrap() - 36.0).abs() < 1e-9);
+
+        let fahrenheit = UnitHandle::find("Temperature", "°F").unwrap();
+        let temperature = Temperature::new_dyn(212.0, &fahrenheit).unwrap();
+        assert!((temperature.get::<degree_celsius>() - 100.0).abs() < 1e-9);
+
+        assert_eq!(
+            Length::new_dyn(1.0, &UnitHandle::of::<second>()),
+            Err(UnitMismatchError {
+                unit: "s",
+                expected: "Length",
+                found: "Time",
+            })
+        );
+        assert!(temperature.get_dyn(&unit).is_err());
+    }
+}
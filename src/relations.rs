@@ -0,0 +1,168 @@
+//! The operators relating measurements to each other, such as `Velocity * Time = Length`.
+//!
+//! Every relation also provides its commuted and inverse forms, see
+//! [`define_relation!`](crate::define_relation).
+
+use crate::{define_relation, prelude::*};
+
+define_relation! {
+    Length ^ 2 = Area;
+    Area * Length = Volume;
+    Velocity * Time = Length;
+    Acceleration * Time = Velocity;
+    Mass * Acceleration = Force;
+    Pressure * Volume = Energy;
+    Pressure * VolumeRate = Power;
+    Voltage * ElectricCurrent = Power;
+    Voltage / ElectricCurrent = Resistance;
+    ElectricCurrent * Time = ElectricCharge;
+    ElectricCharge * Voltage = Energy;
+    Capacitance * Voltage = ElectricCharge;
+    Frequency * Time = f64;
+    Power * Time = Energy;
+    Force * Velocity = Power;
+    Torque * AngularVelocity = Power;
+    Torque * Angle = Energy;
+    MassDensity * Volume = Mass;
+    MassDensity * VolumeRate = MassFlowRate;
+    MassFlowRate * Time = Mass;
+    VolumeRate * Time = Volume;
+    Pressure * Area = Force;
+    Pressure * Time = DynamicViscosity;
+    MolarEnergy * AmountOfSubstance = Energy;
+    MolarMass * AmountOfSubstance = Mass;
+    AngularVelocity * Time = Angle;
+    AngularAcceleration * Time = AngularVelocity;
+    Torque / MomentOfInertia = AngularAcceleration;
+    MomentOfInertia * AngularVelocity = AngularMomentum;
+    Torque * Time = AngularMomentum;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn geometry() {
+        assert_eq!(Length(3.0) * Length(4.0), Area(12.0));
+        assert_eq!(Area(12.0) / Length(4.0), Length(3.0));
+        assert_eq!(Area(12.0) * Length(2.0), Volume(24.0));
+        assert_eq!(Length(2.0) * Area(12.0), Volume(24.0));
+        assert_eq!(Volume(24.0) / Area(12.0), Length(2.0));
+        assert_eq!(Volume(24.0) / Length(2.0), Area(12.0));
+    }
+
+    #[test]
+    fn kinematics() {
+        assert_eq!(Velocity(20.0) * Time(3.0), Length(60.0));
+        assert_eq!(Time(3.0) * Velocity(20.0), Length(60.0));
+        assert_eq!(Length(60.0) / Time(3.0), Velocity(20.0));
+        assert_eq!(Length(60.0) / Velocity(20.0), Time(3.0));
+        assert_eq!(Velocity(20.0) / Time(4.0), Acceleration(5.0));
+        assert_eq!(Velocity(20.0) / Acceleration(5.0), Time(4.0));
+        assert_eq!(Acceleration(5.0) * Time(4.0), Velocity(20.0));
+        assert_eq!(Time(4.0) * Acceleration(5.0), Velocity(20.0));
+        assert_eq!(Acceleration(5.0) * Time(4.0) * Time(2.0), Length(40.0));
+    }
+
+    #[test]
+    fn dynamics() {
+        assert_eq!(Mass(2.0) * Acceleration(3.0), Force(6.0));
+        assert_eq!(Acceleration(3.0) * Mass(2.0), Force(6.0));
+        assert_eq!(Force(6.0) / Mass(2.0), Acceleration(3.0));
+        assert_eq!(Force(6.0) / Acceleration(3.0), Mass(2.0));
+    }
+
+    #[test]
+    fn fluids() {
+        assert_eq!(Pressure(100.0) * Volume(2.0), Energy(200.0));
+        assert_eq!(Power(200.0) / VolumeRate(2.0), Pressure(100.0));
+
+        let water = MassDensity::new::<gram_per_cubic_centimeter>(1.0);
+        let tank = Volume::new::<liter>(500.0);
+        assert!(((water * tank).get::<kilogram>() - 500.0).abs() < 1e-9);
+        assert_eq!(Mass(500.0) / Volume(0.5), MassDensity(1000.0));
+
+        let pipe = VolumeRate::new::<liter_per_second>(2.0);
+        assert!(((pipe * Time(60.0)).get::<liter>() - 120.0).abs() < 1e-9);
+        assert_eq!(Volume(0.12) / Time(60.0), VolumeRate(0.002));
+        assert_eq!(water * pipe, MassFlowRate(2.0));
+        assert_eq!(MassFlowRate(2.0) * Time(60.0), Mass(120.0));
+
+        assert_eq!(Force(300.0) / Area(2.0), Pressure(150.0));
+        assert_eq!(Pressure(150.0) * Area(2.0), Force(300.0));
+        assert_eq!(Pressure(2.0) * Time(0.5), DynamicViscosity(1.0));
+    }
+
+    #[test]
+    fn electricity() {
+        assert_eq!(Voltage(230.0) * ElectricCurrent(2.0), Power(460.0));
+        assert_eq!(Power(460.0) / Voltage(230.0), ElectricCurrent(2.0));
+        assert_eq!(Voltage(12.0) / ElectricCurrent(3.0), Resistance(4.0));
+        assert_eq!(Resistance(4.0) * ElectricCurrent(3.0), Voltage(12.0));
+        assert_eq!(Voltage(12.0) / Resistance(4.0), ElectricCurrent(3.0));
+        assert_eq!(Capacitance(0.5) * Voltage(4.0), ElectricCharge(2.0));
+
+        // A 3000 mA·h battery at 3.7 V stores 11.1 W·h.
+        let charge = ElectricCurrent::new::<milliampere>(3000.0) * Time::new::<hour>(1.0);
+        assert!((charge.get::<milliampere_hour>() - 3000.0).abs() < 1e-9);
+        let energy = charge * Voltage(3.7);
+        assert!((energy.get::<watt_hour>() - 11.1).abs() < 1e-9);
+    }
+
+    #[test]
+    fn frequencies() {
+        assert_eq!(1.0 / Time(0.25), Frequency(4.0));
+        assert_eq!(1.0 / Frequency(4.0), Time(0.25));
+        assert_eq!(Frequency(4.0) * Time(2.0), 8.0);
+        assert_eq!(Time(2.0) * Frequency(4.0), 8.0);
+    }
+
+    #[test]
+    fn work_and_power() {
+        let energy = Power::new::<kilowatt>(2.0) * Time::new::<hour>(3.0);
+        assert!((energy.get::<kilowatt_hour>() - 6.0).abs() < 1e-12);
+        assert_eq!(Energy(600.0) / Time(60.0), Power(10.0));
+        assert_eq!(Energy(600.0) / Power(10.0), Time(60.0));
+        assert_eq!(Force(100.0) * Velocity(3.0), Power(300.0));
+        assert_eq!(Power(300.0) / Velocity(3.0), Force(100.0));
+    }
+
+    #[test]
+    fn rotation() {
+        let torque = Force(100.0).moment(Length(0.5));
+        assert_eq!(torque * AngularVelocity(20.0), Power(1000.0));
+        assert_eq!(Power(1000.0) / AngularVelocity(20.0), torque);
+        assert_eq!(torque * Angle(std::f64::consts::TAU), Energy(100.0 * std::f64::consts::PI));
+        assert_eq!(Energy(100.0) / Angle(2.0), Torque(50.0));
+    }
+
+    #[test]
+    fn chemistry() {
+        // Burning 32 g of methane releases about 1.78 MJ.
+        let methane = Mass::new::<gram>(32.0) / MolarMass::new::<gram_per_mole>(16.04);
+        assert!((methane.get::<mole>() - 1.995).abs() < 1e-3);
+        let heat = MolarEnergy::new::<kilojoule_per_mole>(890.0) * methane;
+        assert!((heat.get::<megajoule>() - 1.7756).abs() < 1e-4);
+
+        assert_eq!(Energy(100.0) / AmountOfSubstance(4.0), MolarEnergy(25.0));
+        assert_eq!(Energy(100.0) / MolarEnergy(25.0), AmountOfSubstance(4.0));
+        assert_eq!(MolarMass(0.5) * AmountOfSubstance(4.0), Mass(2.0));
+        assert_eq!(Mass(2.0) / AmountOfSubstance(4.0), MolarMass(0.5));
+    }
+
+    #[test]
+    fn rotational_dynamics() {
+        assert_eq!(AngularVelocity(2.0) * Time(3.0), Angle(6.0));
+        assert_eq!(Angle(6.0) / Time(3.0), AngularVelocity(2.0));
+        assert_eq!(AngularAcceleration(0.5) * Time(4.0), AngularVelocity(2.0));
+        assert_eq!(AngularVelocity(2.0) / AngularAcceleration(0.5), Time(4.0));
+
+        let wheel = MomentOfInertia(4.0);
+        assert_eq!(Torque(10.0) / wheel, AngularAcceleration(2.5));
+        assert_eq!(wheel * AngularAcceleration(2.5), Torque(10.0));
+        assert_eq!(wheel * AngularVelocity(3.0), AngularMomentum(12.0));
+        assert_eq!(AngularMomentum(12.0) / wheel, AngularVelocity(3.0));
+        assert_eq!(Torque(10.0) * Time(1.2), AngularMomentum(12.0));
+    }
+}
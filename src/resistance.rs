@@ -0,0 +1,23 @@
+use crate::{define_measurement, define_units};
+
+define_measurement! {
+    /// A measurement of electrical resistance, with a base unit of ohms.
+    Resistance = [mass: 1, length: 2, time: -3, current: -2]
+}
+
+define_units! { Resistance =>
+    #[si_prefixes]
+    ohm: ("Ω", 1.0, Si, "ohm", "ohms"),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_works() {
+        let resistance = Resistance::new::<kiloohm>(4.7);
+        assert_eq!(resistance.as_ref(), &4700.0);
+        assert_eq!(resistance.to_string(), "4.70 kΩ");
+    }
+}
@@ -0,0 +1,94 @@
+use crate::{
+    acceleration::Acceleration, define_measurement, define_units, energy::Energy, length::Length,
+    mass::Mass, velocity::AngularVelocity,
+};
+
+define_measurement! {
+    /// A measurement of angular acceleration, with a base unit of radians per second squared.
+    AngularAcceleration = [angle: 1, time: -2]
+}
+
+define_measurement! {
+    /// A measurement of moment of inertia, with a base unit of kilogram square meters
+    /// per square radian.
+    MomentOfInertia = [mass: 1, length: 2, angle: -2]
+}
+
+define_measurement! {
+    /// A measurement of angular momentum, with a base unit of kilogram square meters
+    /// per second per radian.
+    AngularMomentum = [mass: 1, length: 2, time: -1, angle: -1]
+}
+
+define_units! { AngularAcceleration =>
+    radian_per_second_squared: ("rad/s²", 1.0, Si, "radian per second squared", "radians per second squared"),
+    degree_per_second_squared: ("°/s²", std::f64::consts::PI / 180.0, Metric, "degree per second squared", "degrees per second squared"),
+}
+
+define_units! { MomentOfInertia =>
+    #[prefix(KILO)]
+    kilogram_square_meter_per_square_radian: ("kg·m²/rad²", 1.0, Si, "kilogram square meter per square radian", "kilogram square meters per square radian"),
+}
+
+define_units! { AngularMomentum =>
+    #[prefix(KILO)]
+    kilogram_square_meter_per_second_per_radian: ("kg·m²/(s·rad)", 1.0, Si, "kilogram square meter per second per radian", "kilogram square meters per second per radian"),
+}
+
+impl AngularAcceleration {
+    /// The tangential acceleration of a point at `radius` from the axis (`a = αr`).
+    pub fn tangential_acceleration(self, radius: Length) -> Acceleration {
+        Acceleration(self.0 * radius.0)
+    }
+}
+
+impl MomentOfInertia {
+    /// The moment of inertia of a point `mass` at `radius` from the axis (`I = mr²/rad²`).
+    pub fn point_mass(mass: Mass, radius: Length) -> Self {
+        Self(mass.0 * radius.0 * radius.0)
+    }
+
+    /// The rotational kinetic energy when spinning at `angular_velocity` (`E = ½Iω²`), where
+    /// the square radians of the angular velocity cancel those of the moment of inertia.
+    pub fn kinetic_energy(self, angular_velocity: AngularVelocity) -> Energy {
+        Energy(0.5 * self.0 * angular_velocity.0 * angular_velocity.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn it_works() {
+        let acceleration = AngularAcceleration::new::<degree_per_second_squared>(180.0);
+        assert_eq!(acceleration.as_ref(), &std::f64::consts::PI);
+        assert_eq!(MomentOfInertia(2.5).to_string(), "2.50 kg·m²/rad²");
+        assert_eq!(AngularMomentum(4.0).to_string(), "4.00 kg·m²/(s·rad)");
+    }
+
+    #[test]
+    fn wheels() {
+        let wheel = MomentOfInertia::point_mass(Mass(10.0), Length(0.5));
+        assert_eq!(wheel, MomentOfInertia(2.5));
+        assert_eq!(wheel.kinetic_energy(AngularVelocity(4.0)), Energy(20.0));
+        assert_eq!(
+            AngularAcceleration(3.0).tangential_acceleration(Length(0.5)),
+            Acceleration(1.5)
+        );
+    }
+
+    #[test]
+    fn expressions() {
+        let wheel = crate::expression::evaluate("2 kg * (0.5 m)^2 / rad^2").unwrap();
+        assert_eq!(wheel.measurement(), Some("MomentOfInertia"));
+        assert_eq!(wheel.to::<MomentOfInertia>(), Ok(MomentOfInertia(0.5)));
+        assert_eq!(
+            crate::expression::evaluate("0.5 kg·m²/rad² * 4 rad/s")
+                .unwrap()
+                .to::<AngularMomentum>(),
+            Ok(AngularMomentum(2.0))
+        );
+    }
+}
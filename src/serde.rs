@@ -0,0 +1,345 @@
+//! Serde support for measurements.
+//!
+//! Human-readable formats (JSON, RON, TOML, ...) write measurements as a
+//! `"value unit"` string such as `"1 AU"`, and accept either such a string or a
+//! bare number in base units. Binary formats keep the compact `f64` encoding.
+//!
+//! Fields that must always be stored in a specific unit can use the [`in_unit`]
+//! and [`tagged`] adapters instead:
+//!
+//! ```
+//! use besi::prelude::*;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Voyage {
+//!     #[serde(with = "besi::serde::in_unit::<kilometer>")]
+//!     distance: Length,
+//!     #[serde(with = "besi::serde::tagged::<hour>")]
+//!     duration: Time,
+//! }
+//! ```
+
+use std::{fmt, marker::PhantomData};
+
+use ::serde::{Deserializer, Serializer, de, ser::SerializeStruct};
+
+use crate::{
+    BesiUnit, Measurement, ParseMeasurementError, UnitDisplayInfo, parse::find_unit,
+    parse::parse_measurement,
+};
+
+/// Serializes a base-unit `value` of `measurement`.
+#[doc(hidden)]
+pub fn serialize_measurement<S>(
+    measurement: &'static str,
+    value: f64,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    if !serializer.is_human_readable() {
+        return serializer.serialize_f64(value);
+    }
+
+    // Prefer the display unit, but never at the cost of an exact round trip.
+    let best_unit = UnitDisplayInfo::best_for(measurement, value);
+    if let Some(number) = shortest_round_trip(value, best_unit) {
+        return serializer.collect_str(&format_args!("{number} {}", best_unit.symbol));
+    }
+    let unit = UnitDisplayInfo::units_of(measurement)
+        .find(|unit| unit.is_base())
+        .unwrap_or(best_unit);
+    serializer.collect_str(&format_args!("{} {}", unit.from_base(value), unit.symbol))
+}
+
+/// The shortest decimal of `value` in `unit` that converts back to exactly `value`, so
+/// 21.3 °C is written as `21.3` rather than `21.30000000000001`.
+fn shortest_round_trip(value: f64, unit: &UnitDisplayInfo) -> Option<String> {
+    let converted = unit.from_base(value);
+    (0..=17)
+        .map(|precision| format!("{converted:.precision$}"))
+        .chain(std::iter::once(converted.to_string()))
+        .find(|number| number.parse().is_ok_and(|number| unit.to_base(number) == value))
+}
+
+/// Deserializes a base-unit value of `measurement`.
+#[doc(hidden)]
+pub fn deserialize_measurement<'de, D>(
+    measurement: &'static str,
+    deserializer: D,
+) -> Result<f64, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_any(MeasurementVisitor { measurement })
+    } else {
+        deserializer.deserialize_f64(MeasurementVisitor { measurement })
+    }
+}
+
+/// Accepts either a bare number in base units or a `"value unit"` string.
+struct MeasurementVisitor {
+    measurement: &'static str,
+}
+
+impl de::Visitor<'_> for MeasurementVisitor {
+    type Value = f64;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "a number or a string such as \"1.5 km\" for {}",
+            self.measurement
+        )
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<f64, E> {
+        Ok(v)
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<f64, E> {
+        Ok(v as f64)
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<f64, E> {
+        Ok(v as f64)
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<f64, E> {
+        parse_measurement(self.measurement, v).map_err(E::custom)
+    }
+}
+
+/// Serde adapter that stores a measurement as a bare number in the unit `U`.
+///
+/// Deserialization also accepts the `{ value, unit }` form written by [`tagged`] and
+/// `"value unit"` strings, as long as the unit belongs to the same measurement as `U`.
+#[allow(non_camel_case_types)]
+pub struct in_unit<U>(PhantomData<U>);
+
+impl<U: BesiUnit> in_unit<U> {
+    pub fn serialize<S>(value: &U::Measurement, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_f64(U::from_base(value.to_base()))
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<U::Measurement, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(InUnitVisitor::<U>(PhantomData))
+        } else {
+            deserializer.deserialize_f64(InUnitVisitor::<U>(PhantomData))
+        }
+    }
+}
+
+/// Serde adapter that stores a measurement as `{ value, unit }` in the unit `U`.
+///
+/// Deserialization accepts any unit of the same measurement, and the same
+/// inputs as [`in_unit`] in human-readable formats.
+#[allow(non_camel_case_types)]
+pub struct tagged<U>(PhantomData<U>);
+
+impl<U: BesiUnit> tagged<U> {
+    pub fn serialize<S>(value: &U::Measurement, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TaggedMeasurement", 2)?;
+        state.serialize_field("value", &U::from_base(value.to_base()))?;
+        state.serialize_field("unit", U::SYMBOL)?;
+        state.end()
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<U::Measurement, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(InUnitVisitor::<U>(PhantomData))
+        } else {
+            deserializer.deserialize_struct(
+                "TaggedMeasurement",
+                &["value", "unit"],
+                InUnitVisitor::<U>(PhantomData),
+            )
+        }
+    }
+}
+
+/// Accepts a bare number in the unit `U`, a `"value unit"` string or a `{ value, unit }` struct.
+struct InUnitVisitor<U>(PhantomData<U>);
+
+impl<U: BesiUnit> InUnitVisitor<U> {
+    fn tagged<E: de::Error>(value: f64, unit: &str) -> Result<U::Measurement, E> {
+        let info = find_unit(U::Measurement::NAME, unit).map_err(E::custom)?;
+        Self::convert(value, info)
+    }
+
+    /// Converts `value` in `unit`, which must be within the unit's range.
+    fn convert<E: de::Error>(value: f64, unit: &UnitDisplayInfo) -> Result<U::Measurement, E> {
+        unit.try_to_base(value)
+            .map(U::Measurement::from_base)
+            .ok_or_else(|| {
+                let input = format!("{value} {}", unit.symbol);
+                E::custom(ParseMeasurementError::OutOfRange(input))
+            })
+    }
+}
+
+impl<'de, U: BesiUnit> de::Visitor<'de> for InUnitVisitor<U> {
+    type Value = U::Measurement;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "a number in {}, or a {} with a unit",
+            U::SYMBOL,
+            U::Measurement::NAME
+        )
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<Self::Value, E> {
+        Self::convert(v, UnitDisplayInfo::of::<U>())
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+        self.visit_f64(v as f64)
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        self.visit_f64(v as f64)
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        parse_measurement(U::Measurement::NAME, v)
+            .map(U::Measurement::from_base)
+            .map_err(E::custom)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: de::SeqAccess<'de>,
+    {
+        let value: f64 = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let unit: String = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        Self::tagged(value, &unit)
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: de::MapAccess<'de>,
+    {
+        let mut value: Option<f64> = None;
+        let mut unit: Option<String> = None;
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "value" => value = Some(map.next_value()?),
+                "unit" => unit = Some(map.next_value()?),
+                other => return Err(de::Error::unknown_field(other, &["value", "unit"])),
+            }
+        }
+        let value = value.ok_or_else(|| de::Error::missing_field("value"))?;
+        let unit = unit.ok_or_else(|| de::Error::missing_field("unit"))?;
+        Self::tagged(value, &unit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use serde_test::{Configure, Token, assert_de_tokens, assert_tokens};
+
+    #[test]
+    fn readable_uses_unit_strings() {
+        assert_tokens(
+            &Length::new::<astronomical_unit>(1.0).readable(),
+            &[Token::Str("1 AU")],
+        );
+        assert_tokens(&Length(1500.0).readable(), &[Token::Str("1.5 km")]);
+        assert_tokens(&Time(-90.0).readable(), &[Token::Str("-1.5 min")]);
+    }
+
+    #[test]
+    fn readable_accepts_bare_numbers() {
+        assert_de_tokens(&Length(1500.0).readable(), &[Token::F64(1500.0)]);
+        assert_de_tokens(&Length(12.0).readable(), &[Token::U64(12)]);
+        assert_de_tokens(&Length(-12.0).readable(), &[Token::I64(-12)]);
+    }
+
+    #[test]
+    fn readable_round_trips_exactly() {
+        let length = Length(0.1 + 0.2);
+        let json = serde_json::to_string(&length).unwrap();
+        assert_eq!(serde_json::from_str::<Length>(&json).unwrap(), length);
+
+        let temperature = Temperature::new::<degree_celsius>(21.3);
+        let json = serde_json::to_string(&temperature).unwrap();
+        assert_eq!(json, r#""21.3 °C""#);
+        assert_eq!(serde_json::from_str::<Temperature>(&json).unwrap(), temperature);
+    }
+
+    #[test]
+    fn compact_uses_base_f64() {
+        assert_tokens(&Length(1500.0).compact(), &[Token::F64(1500.0)]);
+    }
+
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Voyage {
+        #[serde(with = "crate::serde::in_unit::<kilometer>")]
+        distance: Length,
+        #[serde(with = "crate::serde::tagged::<hour>")]
+        duration: Time,
+    }
+
+    #[test]
+    fn adapters_pin_units() {
+        let voyage = Voyage {
+            distance: Length::new::<kilometer>(1.5),
+            duration: Time::new::<hour>(2.0),
+        };
+        let json = serde_json::to_string(&voyage).unwrap();
+        assert_eq!(
+            json,
+            r#"{"distance":1.5,"duration":{"value":2.0,"unit":"h"}}"#
+        );
+        assert_eq!(serde_json::from_str::<Voyage>(&json).unwrap(), voyage);
+    }
+
+    #[test]
+    fn adapters_accept_other_units_of_the_same_measurement() {
+        let json = r#"{"distance":{"value":1500,"unit":"m"},"duration":"90 min"}"#;
+        let voyage: Voyage = serde_json::from_str(json).unwrap();
+        assert_eq!(voyage.distance, Length(1500.0));
+        assert_eq!(voyage.duration, Time(5400.0));
+
+        let json = r#"{"distance":{"value":1.5,"unit":"kg"},"duration":1}"#;
+        assert!(serde_json::from_str::<Voyage>(json).is_err());
+    }
+
+    #[derive(Debug, serde::Deserialize)]
+    struct Car {
+        #[serde(with = "crate::serde::in_unit::<kilometers_per_liter>")]
+        economy: FuelConsumption,
+    }
+
+    #[test]
+    fn rejects_values_outside_the_unit_range() {
+        assert!(serde_json::from_str::<FuelConsumption>(r#""0 km/L""#).is_err());
+        assert!(serde_json::from_str::<Car>(r#"{"economy":0}"#).is_err());
+        assert!(serde_json::from_str::<Car>(r#"{"economy":{"value":-5,"unit":"mpg"}}"#).is_err());
+        let car: Car = serde_json::from_str(r#"{"economy":20}"#).unwrap();
+        assert!((car.economy.get::<liters_per_100_kilometers>() - 5.0).abs() < 1e-9);
+    }
+}
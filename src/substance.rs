@@ -0,0 +1,42 @@
+use crate::{define_measurement, define_units};
+
+define_measurement! {
+    /// A measurement of an amount of substance, with a base unit of moles.
+    AmountOfSubstance = [amount: 1]
+}
+
+define_measurement! {
+    /// A measurement of molar mass, with a base unit of kilograms per mole.
+    MolarMass = [mass: 1, amount: -1]
+}
+
+define_units! { AmountOfSubstance =>
+    millimole: ("mmol", 1.0e-3, Si, "millimole", "millimoles"),
+    mole: ("mol", 1.0, Si, "mole", "moles"),
+    kilomole: ("kmol", 1.0e3, Si, "kilomole", "kilomoles"),
+}
+
+define_units! { MolarMass =>
+    gram_per_mole: ("g/mol", 1.0e-3, Si, "gram per mole", "grams per mole"),
+    #[prefix(KILO)]
+    kilogram_per_mole: ("kg/mol", 1.0, Si, "kilogram per mole", "kilograms per mole"),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_works() {
+        let amount = AmountOfSubstance::new::<kilomole>(2.5);
+        assert_eq!(amount.as_ref(), &2500.0);
+        assert_eq!(amount.to_string(), "2.50 kmol");
+
+        let water = MolarMass::new::<gram_per_mole>(18.015);
+        assert!((water.get::<kilogram_per_mole>() - 0.018_015).abs() < 1e-12);
+        assert_eq!(
+            "44.01 g/mol".parse::<MolarMass>().map(|m| m.to_string()),
+            Ok("44.01 g/mol".to_string())
+        );
+    }
+}
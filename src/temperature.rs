@@ -1,176 +1,50 @@
-use std::fmt;
-
-/// A measurement of temperature, with a base unit of Kelvin.
-#[cfg_attr(feature = "reflect", derive(bevy_reflect::Reflect))]
-#[derive(Clone, Copy, Default, Debug, PartialEq, PartialOrd)]
-pub struct Temperature(pub f64);
-
-impl Temperature {
-    /// A constant for the zero value.
-    pub const ZERO: Self = Self(0.0);
-
-    /// Creates a new temperature value from a given unit.
-    pub fn new<T: TemperatureUnit>(value: f64) -> Self {
-        Self(T::to_kelvin(value))
-    }
-
-    /// Gets the temperature value in terms of a specific unit.
-    pub fn get<T: TemperatureUnit>(&self) -> f64 {
-        T::from_kelvin(self.0)
-    }
-}
-
-/// A trait for units of temperature.
-pub trait TemperatureUnit {
-    /// Converts a value in this unit to Kelvin.
-    fn to_kelvin(value: f64) -> f64;
-    /// Converts a value from Kelvin to this unit.
-    fn from_kelvin(kelvin: f64) -> f64;
-    /// The symbol for this unit.
-    const SYMBOL: &'static str;
-}
-
-impl fmt::Display for Temperature {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let temp_c = self.get::<degree_celsius>();
-        if temp_c.abs() > 100.0 {
-            write!(f, "{:.2} {}", self.get::<kelvin>(), kelvin::SYMBOL)
-        } else if temp_c.abs() < 1.0 && temp_c.abs() > 1.0e-9 {
-            write!(f, "{:.2} {}", self.get::<kelvin>(), kelvin::SYMBOL)
-        } else {
-            write!(f, "{:.2} {}", temp_c, degree_celsius::SYMBOL)
-        }
-    }
-}
-
-impl serde::Serialize for Temperature {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: serde::Serializer,
-    {
-        self.0.serialize(serializer)
-    }
-}
-
-impl<'de> serde::Deserialize<'de> for Temperature {
-    fn deserialize<De>(deserializer: De) -> Result<Self, De::Error>
-    where
-        De: serde::Deserializer<'de>,
-    {
-        let value: f64 = serde::Deserialize::deserialize(deserializer)?;
-        Ok(Temperature(value))
-    }
-}
-
-// Implement standard math operators
-impl std::ops::Add for Temperature {
-    type Output = Self;
-    fn add(self, rhs: Self) -> Self::Output {
-        Self(self.0 + rhs.0)
-    }
-}
-
-impl std::ops::AddAssign for Temperature {
-    fn add_assign(&mut self, rhs: Self) {
-        self.0 += rhs.0;
-    }
-}
-
-impl std::ops::Sub for Temperature {
-    type Output = Self;
-    fn sub(self, rhs: Self) -> Self::Output {
-        Self(self.0 - rhs.0)
-    }
-}
-
-impl std::ops::SubAssign for Temperature {
-    fn sub_assign(&mut self, rhs: Self) {
-        self.0 -= rhs.0;
-    }
-}
-
-impl std::ops::Mul<f64> for Temperature {
-    type Output = Self;
-    fn mul(self, rhs: f64) -> Self::Output {
-        Self(self.0 * rhs)
-    }
-}
-
-impl std::ops::MulAssign<f64> for Temperature {
-    fn mul_assign(&mut self, rhs: f64) {
-        self.0 *= rhs;
-    }
-}
-
-impl std::ops::Div<f64> for Temperature {
-    type Output = Self;
-    fn div(self, rhs: f64) -> Self::Output {
-        Self(self.0 / rhs)
-    }
-}
-
-impl std::ops::DivAssign<f64> for Temperature {
-    fn div_assign(&mut self, rhs: f64) {
-        self.0 /= rhs;
-    }
-}
-
-impl AsRef<f64> for Temperature {
-    fn as_ref(&self) -> &f64 {
-        &self.0
-    }
-}
-impl AsMut<f64> for Temperature {
-    fn as_mut(&mut self) -> &mut f64 {
-        &mut self.0
-    }
-}
-
-/// Kelvin (K)
-#[derive(Default, Debug, Clone, Copy)]
-#[allow(non_camel_case_types)]
-pub struct kelvin;
-impl TemperatureUnit for kelvin {
-    fn to_kelvin(value: f64) -> f64 {
-        value
-    }
-    fn from_kelvin(k: f64) -> f64 {
-        k
-    }
-    const SYMBOL: &'static str = "K";
-}
-
-/// Celsius (°C)
-#[derive(Default, Debug, Clone, Copy)]
-#[allow(non_camel_case_types)]
-pub struct degree_celsius;
-impl TemperatureUnit for degree_celsius {
-    fn to_kelvin(value: f64) -> f64 {
-        value + 273.15
-    }
-    fn from_kelvin(k: f64) -> f64 {
-        k - 273.15
-    }
-    const SYMBOL: &'static str = "°C";
-}
-
-/// Fahrenheit (°F)
-#[derive(Default, Debug, Clone, Copy)]
-#[allow(non_camel_case_types)]
-pub struct degree_fahrenheit;
-impl TemperatureUnit for degree_fahrenheit {
-    fn to_kelvin(value: f64) -> f64 {
-        (value - 32.0) * 5.0 / 9.0 + 273.15
-    }
-    fn from_kelvin(k: f64) -> f64 {
-        (k - 273.15) * 9.0 / 5.0 + 32.0
-    }
-    const SYMBOL: &'static str = "°F";
+use crate::{define_measurement, define_units};
+
+define_measurement! {
+    /// A difference between two temperatures, with a base unit of kelvins.
+    TemperatureDifference = [temperature: 1]
+}
+
+// Differences are shown with a `Δ`, but the plain temperature symbols are accepted too,
+// as in "a rise of 5 K". Degrees Celsius are as large as kelvins, which are shown instead.
+define_units! { TemperatureDifference =>
+    delta_kelvin: ("ΔK", 1.0, Si, "kelvin", "kelvins"),
+    #[explicit]
+    delta_celsius: ("Δ°C", 1.0, Si, "degree Celsius", "degrees Celsius"),
+    delta_fahrenheit: ("Δ°F", 5.0 / 9.0, Imperial, "degree Fahrenheit", "degrees Fahrenheit"),
+    #[explicit]
+    kelvin_difference: ("K", 1.0, Si, "kelvin", "kelvins"),
+    #[explicit]
+    celsius_difference: ("°C", 1.0, Si, "degree Celsius", "degrees Celsius"),
+    #[explicit]
+    fahrenheit_difference: ("°F", 5.0 / 9.0, Imperial, "degree Fahrenheit", "degrees Fahrenheit"),
+}
+
+define_measurement! {
+    /// A measurement of temperature, with a base unit of Kelvin.
+    ///
+    /// Temperatures are points on a scale: subtracting two of them gives a
+    /// [`TemperatureDifference`], which is what can be added to them.
+    Temperature = [temperature: 1], differences: TemperatureDifference
+}
+
+define_units! { Temperature =>
+    kelvin: ("K", 1.0, Si, "kelvin", "kelvins"),
+    #[offset(273.15)]
+    degree_celsius: ("°C", 1.0, Si, "degree Celsius", "degrees Celsius"),
+    #[offset(273.15 - 32.0 * 5.0 / 9.0)]
+    degree_fahrenheit: ("°F", 5.0 / 9.0, Imperial, "degree Fahrenheit", "degrees Fahrenheit"),
+    #[explicit]
+    degree_rankine: ("°R", 5.0 / 9.0, Imperial, "degree Rankine", "degrees Rankine"),
+    #[explicit]
+    #[offset(273.15)]
+    degree_reaumur: ("°Ré", 1.25, Other, "degree Réaumur", "degrees Réaumur"),
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::prelude::*;
 
     #[test]
     fn conversions() {
@@ -179,6 +53,9 @@ mod tests {
         assert_eq!(temp.get::<degree_celsius>(), 0.0);
         assert!((temp.get::<degree_fahrenheit>() - 32.0).abs() < 1e-9);
 
+        let fahrenheit = crate::registry::find("Temperature", "°F").unwrap();
+        assert!((fahrenheit.to_base(212.0) - 373.15).abs() < 1e-9);
+
         let temp = Temperature::new::<degree_fahrenheit>(32.0);
         assert_eq!(temp.get::<degree_celsius>(), 0.0);
 
@@ -194,9 +71,96 @@ mod tests {
             format!("{}", Temperature::new::<degree_celsius>(100.0)),
             "100.00 °C"
         );
+        assert_eq!(
+            format!("{:>+10.1}", Temperature::new::<degree_celsius>(20.0)),
+            "  +20.0 °C"
+        );
+        assert_eq!(
+            format!("{:#}", Temperature::new::<degree_celsius>(21.5)),
+            "21.5 degrees Celsius"
+        );
+        assert_eq!(format!("{:#}", Temperature::new::<kelvin>(1.0)), "1 kelvin");
         assert_eq!(
             format!("{}", Temperature::new::<degree_celsius>(101.0)),
             "374.15 K"
         );
     }
+
+    #[test]
+    fn display_as() {
+        let boiling = Temperature::new::<degree_celsius>(100.0);
+        assert_eq!(
+            boiling.display_as(&FormatOptions::scientific()).to_string(),
+            "3.73e2 K"
+        );
+        assert_eq!(
+            boiling.display_as(&FormatOptions::significant_figures(2)).to_string(),
+            "100 °C"
+        );
+        assert_eq!(
+            Temperature::new::<kelvin>(5772.0)
+                .display_as(&FormatOptions::engineering())
+                .to_string(),
+            "5.77 kK"
+        );
+    }
+
+    #[test]
+    fn differences() {
+        let room = Temperature::new::<degree_celsius>(20.0);
+        let difference = room - Temperature::new::<degree_celsius>(15.0);
+        assert!((difference.get::<delta_celsius>() - 5.0).abs() < 1e-9);
+        assert!((difference.get::<delta_fahrenheit>() - 9.0).abs() < 1e-9);
+
+        let warmer = room + TemperatureDifference::new::<delta_celsius>(20.0);
+        assert!((warmer.get::<degree_celsius>() - 40.0).abs() < 1e-9);
+        let colder = room - TemperatureDifference::new::<delta_fahrenheit>(36.0);
+        assert!((colder.get::<degree_celsius>()).abs() < 1e-9);
+
+        assert_eq!(TemperatureDifference(2.5).to_string(), "2.50 ΔK");
+        assert_eq!(format!("{:#}", TemperatureDifference(2.0) * 0.5), "1 kelvin");
+    }
+
+    #[test]
+    fn parses_differences_with_temperature_symbols() {
+        assert_eq!(TemperatureDifference::parse("5 K"), Ok(TemperatureDifference(5.0)));
+        assert_eq!(TemperatureDifference::parse("5 °C"), Ok(TemperatureDifference(5.0)));
+        assert_eq!(TemperatureDifference::parse("5 Δ°C"), Ok(TemperatureDifference(5.0)));
+        let fahrenheit = TemperatureDifference::parse("9 °F").unwrap();
+        assert!((fahrenheit.0 - 5.0).abs() < 1e-9);
+
+        // The symbols still mean temperatures when parsing those.
+        assert_eq!(Temperature::parse("5 K"), Ok(Temperature(5.0)));
+        assert_eq!(TemperatureDifference(5.0).to_string(), "5.00 ΔK");
+    }
+
+    #[test]
+    fn explicit_units() {
+        let boiling = Temperature::new::<degree_celsius>(100.0);
+        assert!((boiling.get::<degree_rankine>() - 671.67).abs() < 1e-9);
+        assert!((boiling.get::<degree_reaumur>() - 80.0).abs() < 1e-9);
+        assert_eq!(
+            Temperature::parse("80 °Ré").map(|t| (t.get::<kelvin>() * 100.0).round()),
+            Ok(37315.0)
+        );
+
+        // Rankine and Réaumur are only shown when fixed.
+        let imperial = DisplayPolicy::default().with_systems(&[UnitSystem::Imperial]);
+        assert_eq!(boiling.display_with(&imperial).to_string(), "212.00 °F");
+        let rankine = DisplayPolicy::default().fix::<degree_rankine>();
+        assert_eq!(boiling.display_with(&rankine).to_string(), "671.67 °R");
+    }
+
+    #[test]
+    fn ordering() {
+        let mut temperatures = [
+            Temperature::new::<degree_fahrenheit>(50.0),
+            Temperature::new::<kelvin>(0.0),
+            Temperature::new::<degree_celsius>(5.0),
+        ];
+        temperatures.sort();
+        assert_eq!(temperatures[0], Temperature::ZERO);
+        assert!((temperatures[2].get::<degree_celsius>() - 10.0).abs() < 1e-9);
+        assert_eq!(temperatures.iter().max(), Some(&temperatures[2]));
+    }
 }
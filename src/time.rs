@@ -1,20 +1,72 @@
-use crate::{define_measurement, define_units};
+use std::fmt;
+
+use crate::{define_measurement, define_units, frequency::Frequency, mixed::MixedFormat};
 
 define_measurement! {
     /// A measurement of time, with a base unit of seconds.
-    Time
+    Time = [time: 1]
 }
 
 define_units! { Time =>
-    second: ("s", 1.0),
-    millisecond: ("ms", 0.001),
-    minute: ("min", 60.0),
-    hour: ("h", 3600.0),
-    day: ("d", 86400.0),
-    day_sidereal: ("d'", 86164.09),
-    week: ("w", 604800.0),
-    month: ("mo", 2592000.0),
-    year: ("y", 31536000.0),
+    second: ("s", 1.0, Si, "second", "seconds"),
+    millisecond: ("ms", 0.001, Si, "millisecond", "milliseconds"),
+    minute: ("min", 60.0, Metric, "minute", "minutes"),
+    hour: ("h", 3600.0, Metric, "hour", "hours"),
+    day: ("d", 86400.0, Metric, "day", "days"),
+    day_sidereal: ("d'", 86164.09, Astronomical, "sidereal day", "sidereal days"),
+    week: ("w", 604800.0, Other, "week", "weeks"),
+    month: ("mo", 2592000.0, Other, "month", "months"),
+    year: ("y", 31536000.0, Other, "year", "years"),
+}
+
+impl Time {
+    /// Displays the time as a clock, such as `"01:23:45"`.
+    ///
+    /// Hours are not wrapped at 24, and a formatter precision adds fractional seconds
+    /// (`format!("{:.2}", time.display_clock())` gives `"01:23:45.12"`).
+    pub fn display_clock(&self) -> ClockDisplay {
+        ClockDisplay(*self)
+    }
+
+    /// The frequency of something happening once per this time.
+    pub fn recip(self) -> Frequency {
+        1.0 / self
+    }
+}
+
+/// Displays a [`Time`] as `hh:mm:ss`. Created by [`Time::display_clock`].
+pub struct ClockDisplay(Time);
+
+impl fmt::Display for ClockDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let precision = f.precision().unwrap_or(0);
+        let format = MixedFormat::new()
+            .unit::<hour>()
+            .unit::<minute>()
+            .unit::<second>();
+        let seconds = self.0.0.abs();
+        let scale = 10f64.powi(precision as i32);
+        let total = (seconds * scale).round() / scale;
+        let (mut h, mut m, mut s) = (0.0, 0.0, 0.0);
+        for (count, unit) in format.components(total, precision) {
+            match unit.symbol {
+                "h" => h = count,
+                "min" => m = count,
+                _ => s = count,
+            }
+        }
+
+        let sign = if self.0.0 < 0.0 && total != 0.0 {
+            "-"
+        } else if f.sign_plus() {
+            "+"
+        } else {
+            ""
+        };
+        let width = if precision > 0 { precision + 3 } else { 2 };
+        let text = format!("{sign}{h:02.0}:{m:02.0}:{s:0width$.precision$}");
+        crate::format::pad(f, &text)
+    }
 }
 
 #[cfg(test)]
@@ -50,4 +102,13 @@ mod tests {
         assert_eq!(Time(0.0001).to_string(), "0.10 ms");
         assert_eq!(Time(-3700.0).to_string(), "-1.03 h");
     }
+
+    #[test]
+    fn display_clock_works() {
+        assert_eq!(Time(5025.0).display_clock().to_string(), "01:23:45");
+        assert_eq!(Time(90000.0).display_clock().to_string(), "25:00:00");
+        assert_eq!(Time(59.7).display_clock().to_string(), "00:01:00");
+        assert_eq!(Time(-5.0).display_clock().to_string(), "-00:00:05");
+        assert_eq!(format!("{:.2}", Time(5025.123).display_clock()), "01:23:45.12");
+    }
 }
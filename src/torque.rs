@@ -2,15 +2,12 @@ use crate::{define_measurement, define_units};
 
 define_measurement! {
     /// A measurement of torque, with a base unit of newton meters.
-    Torque
+    Torque = [mass: 1, length: 2, time: -2, angle: -1]
 }
 
 define_units! { Torque =>
-    newton_meter: ("N·m", 1.0),
-    kilonewton_meter: ("kN·m", 1000.0),
-    meganewton_meter: ("MN·m", 1.0e6),
-    teranewton_meter: ("TN·m", 1.0e9),
-    giganewton_meter: ("GN·m", 1.0e12),
+    #[si_prefixes]
+    newton_meter: ("N·m", 1.0, Si, "newton meter", "newton meters"),
 }
 
 #[cfg(test)]
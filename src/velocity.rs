@@ -1,27 +1,27 @@
-use crate::{define_measurement, define_units};
+use crate::{acceleration::Acceleration, define_measurement, define_units, length::Length, time::Time};
 
 define_measurement! {
     /// A measurement of velocity, with a base unit of meters per second.
-    Velocity
+    Velocity = [length: 1, time: -1]
 }
 
 define_measurement! {
     /// A measurement of angular velocity, with a base unit of radians per second.
-    AngularVelocity
+    AngularVelocity = [angle: 1, time: -1]
 }
 
 define_units! { Velocity =>
-    meters_per_second: ("m/s", 1.0),
-    kilometers_per_hour: ("km/h", 1.0 / 3.6),
-    kilometers_per_second: ("km/s", 1000.0),
+    meters_per_second: ("m/s", 1.0, Si, "meter per second", "meters per second"),
+    kilometers_per_hour: ("km/h", 1.0 / 3.6, Metric, "kilometer per hour", "kilometers per hour"),
+    kilometers_per_second: ("km/s", 1000.0, Si, "kilometer per second", "kilometers per second"),
 }
 
 define_units! { AngularVelocity =>
-    radian_per_second: ("rad/s", 1.0),
-    degree_per_second: ("°/s", 1.745_329_251_994_329_5_E-2),
-    revolution_per_second: ("rev/s", 6.283_185_307_179_586_E0),
-    revolution_per_minute: ("rev/min", 1.047_197_551_196_597_7_E-1),
-    revolution_per_hour: ("rev/h", 1.745_329_251_994_329_6_E-3),
+    radian_per_second: ("rad/s", 1.0, Si, "radian per second", "radians per second"),
+    degree_per_second: ("°/s", 1.745_329_251_994_329_5_E-2, Metric, "degree per second", "degrees per second"),
+    revolution_per_second: ("rev/s", std::f64::consts::TAU, Other, "revolution per second", "revolutions per second"),
+    revolution_per_minute: ("rev/min", 1.047_197_551_196_597_7_E-1, Other, "revolution per minute", "revolutions per minute"),
+    revolution_per_hour: ("rev/h", 1.745_329_251_994_329_6_E-3, Other, "revolution per hour", "revolutions per hour"),
 }
 
 impl Velocity {
@@ -29,10 +29,35 @@ impl Velocity {
     pub fn as_c(&self) -> f32 {
         (self.0 / 299_792_458.0) as f32
     }
+
+    /// The distance needed to stop from this velocity, braking at `deceleration` (`s = v²/2a`).
+    pub fn stopping_distance(self, deceleration: Acceleration) -> Length {
+        Length(self.0 * self.0 / (2.0 * deceleration.0.abs()))
+    }
+
+    /// The time needed to stop from this velocity, braking at `deceleration` (`t = v/a`).
+    pub fn stopping_time(self, deceleration: Acceleration) -> Time {
+        Time((self.0 / deceleration.0).abs())
+    }
+}
+
+impl AngularVelocity {
+    /// The speed of a point at `radius` from the axis (`v = ωr`).
+    pub fn tangential_speed(self, radius: Length) -> Velocity {
+        Velocity(self.0 * radius.0)
+    }
+
+    /// The acceleration towards the axis of a point at `radius` from it (`a = ω²r`), which is
+    /// the artificial gravity of a spinning station.
+    pub fn centripetal_acceleration(self, radius: Length) -> Acceleration {
+        Acceleration(self.0 * self.0 * radius.0)
+    }
 }
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::acceleration::standard_gravity;
 
     #[test]
     fn it_works() {
@@ -52,4 +77,22 @@ mod tests {
         assert_eq!(velocity.get::<meters_per_second>(), 10.0);
         assert_eq!(velocity.get::<kilometers_per_hour>(), 36.0);
     }
+
+    #[test]
+    fn stopping() {
+        let speed = Velocity::new::<kilometers_per_hour>(108.0);
+        let braking = Acceleration(-6.0);
+        assert_eq!(speed.stopping_distance(braking), Length(75.0));
+        assert_eq!(speed.stopping_time(braking), Time(5.0));
+        assert_eq!(braking.distance_over(speed, Time(5.0)), Length(75.0));
+    }
+
+    #[test]
+    fn rotating() {
+        let station = AngularVelocity::new::<revolution_per_minute>(2.0);
+        let radius = Length(224.0);
+        assert!((station.tangential_speed(radius).0 - 46.91).abs() < 0.01);
+        let gravity = station.centripetal_acceleration(radius);
+        assert!((gravity.get::<standard_gravity>() - 1.0).abs() < 0.01);
+    }
 }
@@ -0,0 +1,26 @@
+use crate::{define_measurement, define_units};
+
+define_measurement! {
+    /// A measurement of dynamic viscosity, with a base unit of pascal seconds.
+    DynamicViscosity = [mass: 1, length: -1, time: -1]
+}
+
+define_units! { DynamicViscosity =>
+    millipascal_second: ("mPa·s", 1.0e-3, Si, "millipascal second", "millipascal seconds"),
+    pascal_second: ("Pa·s", 1.0, Si, "pascal second", "pascal seconds"),
+    centipoise: ("cP", 1.0e-3, Other, "centipoise", "centipoise"),
+    poise: ("P", 0.1, Other, "poise", "poise"),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_works() {
+        let water = DynamicViscosity::new::<centipoise>(0.89);
+        assert!((water.get::<millipascal_second>() - 0.89).abs() < 1e-12);
+        assert_eq!(water.to_string(), "0.89 mPa·s");
+        assert_eq!(DynamicViscosity::new::<poise>(1.0), DynamicViscosity(0.1));
+    }
+}
@@ -0,0 +1,23 @@
+use crate::{define_measurement, define_units};
+
+define_measurement! {
+    /// A measurement of electric potential difference, with a base unit of volts.
+    Voltage = [mass: 1, length: 2, time: -3, current: -1]
+}
+
+define_units! { Voltage =>
+    #[si_prefixes]
+    volt: ("V", 1.0, Si, "volt", "volts"),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_works() {
+        let voltage = Voltage::new::<kilovolt>(11.0);
+        assert_eq!(voltage.as_ref(), &11000.0);
+        assert_eq!("230 V".parse(), Ok(Voltage(230.0)));
+    }
+}
@@ -2,13 +2,13 @@ use crate::{define_measurement, define_units};
 
 define_measurement! {
     /// A measurement of volume, with a base unit of cubic meters.
-    Volume
+    Volume = [length: 3]
 }
 
 define_units! { Volume =>
-    cubic_meter: ("m³", 1.0),
-    liter: ("L", 0.001),
-    milliliter: ("mL", 1.0e-6),
+    cubic_meter: ("m³", 1.0, Si, "cubic meter", "cubic meters"),
+    liter: ("L", 0.001, Metric, "liter", "liters"),
+    milliliter: ("mL", 1.0e-6, Metric, "milliliter", "milliliters"),
 }
 
 #[cfg(test)]